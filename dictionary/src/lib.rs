@@ -12,7 +12,7 @@ use flate2::bufread::GzDecoder;
 use numformat::NumFormat;
 
 /// Word end and next tree node indicators
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LetterNext {
     /// No word with this letter in this position
     None,
@@ -31,6 +31,8 @@ type LetterVec = [LetterNext; 26];
 pub struct Dictionary {
     words: usize,
     tree: Vec<LetterVec>,
+    max_depth: Vec<u8>,
+    words_below: Vec<u32>,
 }
 
 impl Dictionary {
@@ -185,7 +187,15 @@ impl Dictionary {
             }
         }
 
-        let dictionary = Self { words, tree };
+        let max_depth = Self::calc_max_depth(&tree);
+        let words_below = Self::calc_words_below(&tree);
+
+        let dictionary = Self {
+            words,
+            tree,
+            max_depth,
+            words_below,
+        };
 
         if verbose {
             println!(
@@ -239,6 +249,164 @@ impl Dictionary {
         self.tree[elem][letter as usize]
     }
 
+    /// Returns the maximum number of further letters which can be added to a word at the
+    /// given dictionary tree node
+    #[inline]
+    pub fn elem_max_depth(&self, elem: usize) -> usize {
+        self.max_depth[elem] as usize
+    }
+
+    /// Calculates the maximum remaining word length for each tree node
+    fn calc_max_depth(tree: &[LetterVec]) -> Vec<u8> {
+        let mut max_depth = vec![0u8; tree.len()];
+
+        // Child nodes are always created after their parent so walk the tree backwards
+        for elem in (0..tree.len()).rev() {
+            max_depth[elem] = tree[elem]
+                .iter()
+                .map(|next| match next {
+                    LetterNext::None => 0,
+                    LetterNext::End => 1,
+                    LetterNext::Next(e) | LetterNext::EndNext(e) => {
+                        max_depth[*e as usize].saturating_add(1)
+                    }
+                })
+                .max()
+                .unwrap_or(0);
+        }
+
+        max_depth
+    }
+
+    /// Returns the alphabetical position of a word (upper case A-Z) in the dictionary
+    pub fn word_id(&self, word: &str) -> Option<usize> {
+        let mut id = 0;
+        let mut elem = 0;
+
+        for (i, c) in word.chars().enumerate() {
+            if !c.is_ascii_uppercase() {
+                return None;
+            }
+
+            let letter = (c as u8 - b'A') as usize;
+
+            // Count words in earlier branches
+            id += self.tree[elem][..letter]
+                .iter()
+                .map(|next| self.entry_word_count(next))
+                .sum::<usize>();
+
+            let next = self.tree[elem][letter];
+
+            if i == word.len() - 1 {
+                return match next {
+                    LetterNext::End | LetterNext::EndNext(_) => Some(id),
+                    _ => None,
+                };
+            }
+
+            elem = match next {
+                LetterNext::Next(e) => e as usize,
+                LetterNext::EndNext(e) => {
+                    // Shorter word comes first
+                    id += 1;
+                    e as usize
+                }
+                _ => return None,
+            };
+        }
+
+        None
+    }
+
+    /// Returns all of the words in the dictionary in alphabetical order (upper case)
+    pub fn words(&self) -> Vec<String> {
+        let mut words = Vec::with_capacity(self.words);
+
+        self.words_rec(0, &mut String::new(), None, &mut words);
+
+        words
+    }
+
+    /// Returns all of the words of the given length in the dictionary in alphabetical order
+    /// (upper case)
+    pub fn words_with_len(&self, len: usize) -> Vec<String> {
+        let mut words = Vec::new();
+
+        self.words_rec(0, &mut String::with_capacity(len), Some(len), &mut words);
+
+        words
+    }
+
+    fn words_rec(
+        &self,
+        elem: usize,
+        word: &mut String,
+        len: Option<usize>,
+        words: &mut Vec<String>,
+    ) {
+        if let Some(len) = len {
+            if word.len() + self.elem_max_depth(elem) < len {
+                // Word length can't be reached from this node
+                return;
+            }
+        }
+
+        for (letter, next) in self.tree[elem].iter().enumerate() {
+            word.push((b'A' + letter as u8) as char);
+
+            if let LetterNext::End | LetterNext::EndNext(_) = next {
+                if len.is_none() || len == Some(word.len()) {
+                    words.push(word.clone());
+                }
+            }
+
+            if let LetterNext::Next(e) | LetterNext::EndNext(e) = next {
+                if len.is_none_or(|len| word.len() < len) {
+                    self.words_rec(*e as usize, word, len, words);
+                }
+            }
+
+            word.pop();
+        }
+    }
+
+    /// Returns true if the word (upper case A-Z) is in the dictionary
+    pub fn contains(&self, word: &str) -> bool {
+        self.word_id(word).is_some()
+    }
+
+    /// Returns the number of words reachable from a tree node entry
+    #[inline]
+    fn entry_word_count(&self, next: &LetterNext) -> usize {
+        match next {
+            LetterNext::None => 0,
+            LetterNext::End => 1,
+            LetterNext::Next(e) => self.words_below[*e as usize] as usize,
+            LetterNext::EndNext(e) => 1 + self.words_below[*e as usize] as usize,
+        }
+    }
+
+    /// Calculates the number of words below each tree node
+    fn calc_words_below(tree: &[LetterVec]) -> Vec<u32> {
+        let mut words_below = vec![0u32; tree.len()];
+
+        // Child nodes are always created after their parent so walk the tree backwards
+        for elem in (0..tree.len()).rev() {
+            words_below[elem] = tree[elem]
+                .iter()
+                .map(|next| match next {
+                    LetterNext::None => 0,
+                    LetterNext::End => 1,
+                    LetterNext::Next(e) => words_below[*e as usize],
+                    LetterNext::EndNext(e) => 1 + words_below[*e as usize],
+                })
+                .sum();
+        }
+
+        words_below
+    }
+
     #[inline]
     fn lchar_to_elem(c: char) -> usize {
         (c as u8 - b'a') as usize
@@ -344,6 +512,9 @@ mod tests {
             dictionary.lookup_elem_letter_num(3, b'T' - b'A'),
             LetterNext::End
         ));
+
+        assert_eq!(dictionary.elem_max_depth(0), 4);
+        assert_eq!(dictionary.elem_max_depth(3), 1);
     }
 
     #[test]
@@ -389,5 +560,36 @@ mod tests {
             dictionary.lookup_elem_letter_num(4, b'Y' - b'A'),
             LetterNext::End
         ));
+
+        assert_eq!(dictionary.elem_max_depth(0), 5);
+        assert_eq!(dictionary.elem_max_depth(3), 2);
+        assert_eq!(dictionary.elem_max_depth(4), 1);
+    }
+
+    #[test]
+    fn word_ids() {
+        let dictionary =
+            Dictionary::new_from_string("rust\nrusty\nrut\naaa\nxxx", Default::default(), false)
+                .unwrap();
+
+        assert_eq!(dictionary.word_id("AAA"), Some(0));
+        assert_eq!(dictionary.word_id("RUST"), Some(1));
+        assert_eq!(dictionary.word_id("RUSTY"), Some(2));
+        assert_eq!(dictionary.word_id("RUT"), Some(3));
+        assert_eq!(dictionary.word_id("XXX"), Some(4));
+
+        assert_eq!(dictionary.word_id("RUS"), None);
+        assert_eq!(dictionary.word_id("RUSTS"), None);
+        assert_eq!(dictionary.word_id("rust"), None);
+        assert_eq!(dictionary.word_id(""), None);
+
+        assert!(dictionary.contains("RUSTY"));
+        assert!(!dictionary.contains("RUSTYX"));
+
+        assert_eq!(
+            dictionary.words(),
+            vec!["AAA", "RUST", "RUSTY", "RUT", "XXX"]
+        );
+        assert_eq!(dictionary.words_with_len(3), vec!["AAA", "RUT", "XXX"]);
     }
 }
@@ -0,0 +1,334 @@
+//! Daily challenge
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use dictionary::Dictionary;
+use solver::{find_words, LetterPiles, PileFrequencies, PileKind, Rng, SolverArgs};
+
+use crate::round::ROUND_LETTERS;
+
+/// Maximum number of draws tried before giving up
+const MAX_ATTEMPTS: usize = 10_000;
+
+/// A calendar date (UTC)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    /// Year
+    pub year: i32,
+    /// Month (1-12)
+    pub month: u32,
+    /// Day of the month (1 based)
+    pub day: u32,
+}
+
+impl Date {
+    /// Creates a date. Returns None if the date is not valid
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns today's date (UTC)
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self::from_days((secs / 86_400) as i64)
+    }
+
+    /// Creates a date from the number of days since 1970-01-01
+    pub fn from_days(days: i64) -> Self {
+        // Civil from days algorithm (Howard Hinnant)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+
+        Self { year, month, day }
+    }
+
+    /// Returns the number of days since 1970-01-01
+    pub fn days(&self) -> i64 {
+        // Days from civil algorithm (Howard Hinnant)
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = (i64::from(self.month) + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Returns the random number seed for the date
+    pub fn seed(&self) -> u64 {
+        Rng::new(self.days() as u64).next_u64()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DateError(s.to_string());
+
+        let mut parts = s.trim().splitn(3, '-');
+
+        let mut next = || parts.next().ok_or_else(err);
+
+        let year = next()?.parse().map_err(|_| err())?;
+        let month = next()?.parse().map_err(|_| err())?;
+        let day = next()?.parse().map_err(|_| err())?;
+
+        Date::new(year, month, day).ok_or_else(err)
+    }
+}
+
+/// Invalid date error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateError(pub String);
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid YYYY-MM-DD date", self.0)
+    }
+}
+
+impl Error for DateError {}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Quality rules for daily letter sets
+#[derive(Debug, Clone, Copy)]
+pub struct DailyRules {
+    /// Minimum length of the best word
+    pub min_best_len: usize,
+    /// Maximum number of words of the best length
+    pub max_best_words: usize,
+}
+
+impl Default for DailyRules {
+    fn default() -> Self {
+        Self {
+            min_best_len: 7,
+            max_best_words: 5,
+        }
+    }
+}
+
+/// A daily challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Daily {
+    /// The date of the challenge
+    pub date: Date,
+    /// The letters
+    pub letters: String,
+    /// Length of the best word
+    pub best_len: usize,
+    /// The words of the best length
+    pub best_words: Vec<String>,
+}
+
+impl Daily {
+    /// Draws the letters for a date. Draws are repeated from the date seed until the best word
+    /// satisfies the rules so every player gets the same letters for the date. Returns None if no
+    /// suitable draw is found
+    pub fn new(
+        date: Date,
+        dictionary: &Dictionary,
+        piles: &PileFrequencies,
+        rules: &DailyRules,
+    ) -> Option<Self> {
+        let mut rng = Rng::new(date.seed());
+
+        for _ in 0..MAX_ATTEMPTS {
+            let letters = draw(piles, &mut rng)?;
+
+            let words = find_words(SolverArgs::new(&letters, dictionary));
+
+            let best_len = words.iter().map(|w| w.len()).max().unwrap_or(0);
+
+            if best_len < rules.min_best_len {
+                continue;
+            }
+
+            let best_words = words
+                .into_iter()
+                .filter(|w| w.len() == best_len)
+                .collect::<Vec<_>>();
+
+            if best_words.len() > rules.max_best_words {
+                continue;
+            }
+
+            return Some(Self {
+                date,
+                letters,
+                best_len,
+                best_words,
+            });
+        }
+
+        None
+    }
+
+    /// Returns a short code for the letters which doesn't give them away. Players can compare
+    /// codes to check they had the same letters, as the letters also depend on the word list and
+    /// the piles
+    pub fn code(&self) -> String {
+        // FNV-1a hash of the letters
+        let hash = self
+            .letters
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+                (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+            });
+
+        format!("{:04X}", hash >> 48)
+    }
+
+    /// Returns a shareable summary of a player's result which doesn't give away the letters or
+    /// any words
+    pub fn summary(&self, word_len: Option<usize>, score: u32, time: Duration) -> String {
+        let found = word_len.unwrap_or(0);
+
+        let squares = (0..self.best_len.max(found))
+            .map(|i| if i < found { '🟩' } else { '⬜' })
+            .collect::<String>();
+
+        let result = match word_len {
+            Some(len) => format!("{len}/{}", self.best_len),
+            None => format!("X/{}", self.best_len),
+        };
+
+        format!(
+            "Countdown daily {} #{}\n{result} {squares}\n{score} {} in {}s",
+            self.date,
+            self.code(),
+            if score == 1 { "point" } else { "points" },
+            time.as_secs()
+        )
+    }
+}
+
+/// Draws a letter set with a random vowel and consonant split allowed by the Countdown rules
+fn draw(piles: &PileFrequencies, rng: &mut Rng) -> Option<String> {
+    let vowels = 3 + rng.below(3);
+
+    let mut choices = std::iter::repeat_n(PileKind::Vowel, vowels)
+        .chain(std::iter::repeat_n(
+            PileKind::Consonant,
+            ROUND_LETTERS - vowels,
+        ))
+        .collect::<Vec<_>>();
+
+    // Shuffle the choices
+    for i in (1..choices.len()).rev() {
+        choices.swap(i, rng.below(i + 1));
+    }
+
+    let mut piles = LetterPiles::new(piles);
+
+    choices
+        .into_iter()
+        .map(|kind| piles.draw(kind, rng).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        let date: Date = "2024-02-29".parse().unwrap();
+
+        assert_eq!(date, Date::new(2024, 2, 29).unwrap());
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(Date::from_days(date.days()), date);
+        assert_eq!(Date::new(1970, 1, 1).unwrap().days(), 0);
+        assert_eq!(Date::from_days(-1), Date::new(1969, 12, 31).unwrap());
+
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2023-13-01".parse::<Date>().is_err());
+        assert!("2023-1".parse::<Date>().is_err());
+        assert!("yesterday".parse::<Date>().is_err());
+
+        assert_ne!(date.seed(), Date::new(2024, 3, 1).unwrap().seed());
+    }
+
+    #[test]
+    fn daily() {
+        let dictionary = Dictionary::new_from_string(
+            "rat\nstar\ntrain\nstrain\nsatire\nretains\nstainer\nnastier",
+            Default::default(),
+            false,
+        )
+        .unwrap();
+
+        let piles = PileFrequencies {
+            vowels: "A5 E5 I5".parse().unwrap(),
+            consonants: "N5 R5 S5 T5".parse().unwrap(),
+        };
+
+        let rules = DailyRules {
+            min_best_len: 6,
+            max_best_words: 2,
+        };
+
+        let date = Date::new(2024, 6, 1).unwrap();
+
+        let daily = Daily::new(date, &dictionary, &piles, &rules).unwrap();
+
+        assert_eq!(daily.letters.len(), ROUND_LETTERS);
+        assert!(daily.best_len >= 6);
+        assert!(!daily.best_words.is_empty() && daily.best_words.len() <= 2);
+
+        // Same date gives the same letters
+        assert_eq!(
+            Daily::new(date, &dictionary, &piles, &rules),
+            Some(daily.clone())
+        );
+
+        // Summary doesn't give away the letters
+        let summary = daily.summary(Some(5), 5, Duration::from_secs(12));
+
+        assert!(summary.starts_with(&format!("Countdown daily 2024-06-01 #{}\n5/", daily.code())));
+        assert!(summary.ends_with("5 points in 12s"));
+        assert!(!summary.contains(&daily.letters));
+
+        // Different letters give a different code
+        let other = Daily {
+            letters: "ABCDEFGHI".into(),
+            ..daily.clone()
+        };
+
+        assert_eq!(daily.code().len(), 4);
+        assert_ne!(daily.code(), other.code());
+    }
+}
@@ -0,0 +1,294 @@
+//! Spaced repetition drill
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use solver::Rng;
+
+use crate::daily::Date;
+use crate::history::{history_stats, HistoryEntry, Period};
+
+/// Minimum ease factor
+const MIN_EASE: f64 = 1.3;
+
+/// How well a word was recalled in a review
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grade {
+    /// Not found
+    Again,
+    /// Found with difficulty
+    Hard,
+    /// Found
+    Good,
+    /// Found easily
+    Easy,
+}
+
+/// A word to drill and its review schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    /// The word (upper case)
+    pub word: String,
+    /// Days until the next review after the last review
+    pub interval: u32,
+    /// Ease factor, multiplies the interval after a successful review
+    pub ease: f64,
+    /// Day the card is next due (days since 1970-01-01)
+    pub due: i64,
+    /// Number of successful reviews in a row
+    pub streak: u32,
+    /// Number of times the word was not found
+    pub lapses: u32,
+}
+
+impl Card {
+    /// Creates a card due today
+    pub fn new(word: &str, today: Date) -> Self {
+        Self {
+            word: word.to_ascii_uppercase(),
+            interval: 0,
+            ease: 2.5,
+            due: today.days(),
+            streak: 0,
+            lapses: 0,
+        }
+    }
+
+    /// Returns the date the card is next due
+    pub fn due_date(&self) -> Date {
+        Date::from_days(self.due)
+    }
+
+    /// Reschedules the card after a review (SM-2 algorithm)
+    pub fn review(&mut self, grade: Grade, today: Date) {
+        let interval = match (grade, self.streak) {
+            (Grade::Again, _) => 1.0,
+            (Grade::Hard, _) => (self.interval as f64 * 1.2).max(1.0),
+            (_, 0) => 1.0,
+            (_, 1) => 3.0,
+            _ => self.interval as f64 * self.ease,
+        };
+
+        let interval = if grade == Grade::Easy {
+            interval * 1.3
+        } else {
+            interval
+        };
+
+        match grade {
+            Grade::Again => {
+                self.streak = 0;
+                self.lapses += 1;
+                self.ease -= 0.2;
+            }
+            Grade::Hard => {
+                self.streak += 1;
+                self.ease -= 0.15;
+            }
+            Grade::Good => self.streak += 1,
+            Grade::Easy => {
+                self.streak += 1;
+                self.ease += 0.15;
+            }
+        }
+
+        self.ease = self.ease.max(MIN_EASE);
+        self.interval = interval.round() as u32;
+        self.due = today.days() + i64::from(self.interval);
+    }
+}
+
+/// Drill review schedule for a set of words
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DrillSchedule {
+    cards: Vec<Card>,
+}
+
+impl DrillSchedule {
+    /// Creates an empty schedule
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a schedule from a JSON file. A missing file gives an empty schedule
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Saves the schedule to a JSON file
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+
+        fs::write(path, json + "\n")
+    }
+
+    /// Returns the cards
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns a card to update after a review
+    pub fn card_mut(&mut self, index: usize) -> &mut Card {
+        &mut self.cards[index]
+    }
+
+    /// Adds a word due today. Returns false if the word is already in the schedule
+    pub fn add(&mut self, word: &str, today: Date) -> bool {
+        let word = word.to_ascii_uppercase();
+
+        if self.cards.iter().any(|c| c.word == word) {
+            false
+        } else {
+            self.cards.push(Card::new(&word, today));
+            true
+        }
+    }
+
+    /// Returns the indexes of the cards due on or before a date, most overdue first
+    pub fn due(&self, today: Date) -> Vec<usize> {
+        let mut due = (0..self.cards.len())
+            .filter(|&i| self.cards[i].due <= today.days())
+            .collect::<Vec<_>>();
+
+        due.sort_by(|&a, &b| {
+            let (a, b) = (&self.cards[a], &self.cards[b]);
+            a.due.cmp(&b.due).then_with(|| a.word.cmp(&b.word))
+        });
+
+        due
+    }
+
+    /// Returns the date the next card is due
+    pub fn next_due(&self) -> Option<Date> {
+        self.cards.iter().map(|c| c.due).min().map(Date::from_days)
+    }
+}
+
+/// Returns the best words of at least `min_len` letters missed in a history, most missed first
+pub fn missed_words(entries: &[HistoryEntry], min_len: usize) -> Vec<String> {
+    let stats = history_stats(entries, Period::Day);
+
+    stats
+        .missed
+        .into_iter()
+        .map(|(word, _)| word)
+        .filter(|w| w.len() >= min_len)
+        .collect()
+}
+
+/// Shuffles the letters of a word. The word itself is never returned unless all of its letters
+/// are the same
+pub fn scramble(word: &str, rng: &mut Rng) -> String {
+    let mut letters = word.chars().collect::<Vec<_>>();
+
+    let distinct = letters.iter().collect::<HashSet<_>>().len();
+
+    loop {
+        for i in (1..letters.len()).rev() {
+            letters.swap(i, rng.below(i + 1));
+        }
+
+        let scrambled = letters.iter().collect::<String>();
+
+        if scrambled != word || distinct <= 1 {
+            return scrambled;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn review() {
+        let today = Date::new(2024, 1, 1).unwrap();
+
+        let mut card = Card::new("stainer", today);
+
+        assert_eq!(card.word, "STAINER");
+        assert_eq!(card.due_date(), today);
+
+        card.review(Grade::Good, today);
+        assert_eq!((card.interval, card.streak), (1, 1));
+
+        card.review(Grade::Good, today);
+        assert_eq!(card.interval, 3);
+
+        card.review(Grade::Good, today);
+        assert_eq!(card.interval, 8);
+        assert_eq!(card.due_date(), Date::new(2024, 1, 9).unwrap());
+
+        card.review(Grade::Easy, today);
+        assert_eq!(card.interval, 26);
+        assert_eq!(card.ease, 2.65);
+
+        card.review(Grade::Again, today);
+        assert_eq!((card.interval, card.streak, card.lapses), (1, 0, 1));
+        assert!((card.ease - 2.45).abs() < 1e-9);
+
+        for _ in 0..20 {
+            card.review(Grade::Hard, today);
+        }
+
+        assert_eq!(card.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn schedule() {
+        let today = Date::new(2024, 1, 1).unwrap();
+        let tomorrow = Date::from_days(today.days() + 1);
+
+        let mut schedule = DrillSchedule::new();
+
+        assert!(schedule.add("nastier", today));
+        assert!(schedule.add("ANDROID", today));
+        assert!(!schedule.add("Nastier", today));
+
+        assert_eq!(schedule.due(today), vec![1, 0]);
+
+        schedule.card_mut(1).review(Grade::Good, today);
+
+        assert_eq!(schedule.due(today), vec![0]);
+        assert_eq!(schedule.due(tomorrow), vec![0, 1]);
+        assert_eq!(schedule.next_due(), Some(today));
+
+        let path = env::temp_dir().join(format!("countdown_drill_{}.json", std::process::id()));
+
+        schedule.save(&path).unwrap();
+        assert_eq!(DrillSchedule::load(&path).unwrap(), schedule);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(DrillSchedule::load(&path).unwrap(), DrillSchedule::new());
+    }
+
+    #[test]
+    fn scrambles() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..20 {
+            let scrambled = scramble("AB", &mut rng);
+            assert_eq!(scrambled, "BA");
+        }
+
+        let mut scrambled = scramble("STAINER", &mut rng).into_bytes();
+        scrambled.sort();
+        assert_eq!(scrambled, b"AEINRST");
+
+        assert_eq!(scramble("AA", &mut rng), "AA");
+    }
+}
@@ -0,0 +1,279 @@
+//! Episode of letters rounds and a conundrum
+
+use std::time::Duration;
+
+use dictionary::Dictionary;
+use solver::{
+    Conundrum, ConundrumGenerator, CountdownScorer, LetterPiles, PileFrequencies, PileKind, Rng,
+    Scorer,
+};
+
+use crate::round::{Declaration, Round, RoundError};
+
+/// Default number of letters rounds in an episode
+pub const EPISODE_ROUNDS: usize = 4;
+
+/// Score for solving the conundrum
+pub const CONUNDRUM_SCORE: u32 = 10;
+
+/// Stage of an episode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// A letters round (1 based)
+    Letters(usize),
+    /// The conundrum
+    Conundrum,
+    /// The episode is over
+    Finished,
+}
+
+/// Result of a letters round in an episode
+#[derive(Debug, Clone, PartialEq)]
+pub struct LettersResult {
+    /// The letters
+    pub letters: String,
+    /// The word declared
+    pub declaration: Declaration,
+    /// The longest words available
+    pub best: Vec<String>,
+    /// Score for the longest words available
+    pub best_score: u32,
+    /// Time taken to find the word
+    pub taken: Duration,
+}
+
+/// Result of the conundrum in an episode
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConundrumResult {
+    /// The conundrum
+    pub conundrum: Conundrum,
+    /// The answer given (upper case), if the buzzer was pressed
+    pub guess: Option<String>,
+    /// Time until the buzzer was pressed, or the full time if it wasn't
+    pub taken: Duration,
+    /// Score for the conundrum
+    pub score: u32,
+}
+
+impl ConundrumResult {
+    /// Returns true if the conundrum was solved
+    pub fn is_solved(&self) -> bool {
+        self.score > 0
+    }
+}
+
+/// An episode of letters rounds followed by a conundrum. Letters are drawn from weighted piles
+/// which are refilled for each round, and words are scored with the Countdown rules
+pub struct Episode<'a> {
+    dictionary: &'a Dictionary,
+    frequencies: PileFrequencies,
+    rounds: usize,
+    rng: Rng,
+    piles: LetterPiles,
+    letters: Vec<LettersResult>,
+    conundrum: Option<Conundrum>,
+    conundrum_result: Option<ConundrumResult>,
+    finished: bool,
+}
+
+impl<'a> Episode<'a> {
+    /// Creates an episode with a number of letters rounds
+    pub fn new(
+        dictionary: &'a Dictionary,
+        frequencies: PileFrequencies,
+        rounds: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            dictionary,
+            piles: LetterPiles::new(&frequencies),
+            frequencies,
+            rounds,
+            rng: Rng::new(seed),
+            letters: Vec::new(),
+            conundrum: None,
+            conundrum_result: None,
+            finished: false,
+        }
+    }
+
+    /// Returns the current stage of the episode
+    pub fn stage(&self) -> Stage {
+        if self.letters.len() < self.rounds {
+            Stage::Letters(self.letters.len() + 1)
+        } else if !self.finished {
+            Stage::Conundrum
+        } else {
+            Stage::Finished
+        }
+    }
+
+    /// Starts the next letters round with full letter piles
+    pub fn new_round(&mut self) -> Round<'a> {
+        self.piles = LetterPiles::new(&self.frequencies);
+
+        Round::new(self.dictionary)
+    }
+
+    /// Chooses the next letter in a round from one of the piles
+    pub fn choose(&mut self, round: &mut Round, kind: PileKind) -> Result<char, RoundError> {
+        round.choose(kind, &mut self.piles, &mut self.rng)
+    }
+
+    /// Scores the word declared in a letters round and moves on to the next stage
+    pub fn declare(&mut self, round: &Round, word: &str, taken: Duration) -> &LettersResult {
+        let best = round.best_words();
+
+        self.letters.push(LettersResult {
+            letters: round.letters().to_string(),
+            declaration: round.declare(word),
+            best_score: best.first().map_or(0, |w| CountdownScorer.score(w)),
+            best,
+            taken,
+        });
+
+        self.letters.last().unwrap()
+    }
+
+    /// Generates the conundrum. Returns None and finishes the episode if no conundrum can be made
+    /// from the dictionary
+    pub fn new_conundrum(&mut self, generator: &ConundrumGenerator) -> Option<&Conundrum> {
+        self.conundrum = generator.generate(&mut self.rng);
+
+        if self.conundrum.is_none() {
+            self.finished = true;
+        }
+
+        self.conundrum.as_ref()
+    }
+
+    /// Scores the answer to the conundrum, given when the buzzer was pressed (None if the time ran
+    /// out), and finishes the episode. Returns None if there is no conundrum to answer
+    pub fn answer_conundrum(
+        &mut self,
+        guess: Option<&str>,
+        taken: Duration,
+    ) -> Option<&ConundrumResult> {
+        let conundrum = self.conundrum.take()?;
+
+        let guess = guess.map(|g| g.trim().to_ascii_uppercase());
+
+        let score = if guess.as_deref() == Some(conundrum.answer.as_str()) {
+            CONUNDRUM_SCORE
+        } else {
+            0
+        };
+
+        self.finished = true;
+
+        Some(self.conundrum_result.insert(ConundrumResult {
+            conundrum,
+            guess,
+            taken,
+            score,
+        }))
+    }
+
+    /// Returns the results of the letters rounds played so far
+    pub fn letters_results(&self) -> &[LettersResult] {
+        &self.letters
+    }
+
+    /// Returns the result of the conundrum if it has been played
+    pub fn conundrum_result(&self) -> Option<&ConundrumResult> {
+        self.conundrum_result.as_ref()
+    }
+
+    /// Returns the total score so far
+    pub fn score(&self) -> u32 {
+        self.letters
+            .iter()
+            .map(|r| r.declaration.score)
+            .sum::<u32>()
+            + self.conundrum_result.as_ref().map_or(0, |r| r.score)
+    }
+
+    /// Returns the highest total score possible for the rounds played so far
+    pub fn max_score(&self) -> u32 {
+        self.letters.iter().map(|r| r.best_score).sum::<u32>()
+            + self
+                .conundrum_result
+                .as_ref()
+                .map_or(0, |_| CONUNDRUM_SCORE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn episode() {
+        let dictionary = Dictionary::new_from_string(
+            "rat\ntar\nart\nstar\ntrain\nsatire\nnastier\ncountdown\ncount\ndown",
+            Default::default(),
+            false,
+        )
+        .unwrap();
+
+        let mut episode = Episode::new(&dictionary, PileFrequencies::countdown(), 2, 1);
+
+        assert_eq!(episode.stage(), Stage::Letters(1));
+
+        for round_number in 1..=2 {
+            assert_eq!(episode.stage(), Stage::Letters(round_number));
+
+            let mut round = episode.new_round();
+
+            for kind in solver::parse_choices("VCCVCCVCC").unwrap() {
+                episode.choose(&mut round, kind).unwrap();
+            }
+
+            assert!(round.is_complete());
+
+            // Replace the random letters with known ones
+            round.set_letters("NASTIERXY");
+
+            let result = episode.declare(&round, "star", Duration::from_secs(5));
+
+            assert_eq!(result.declaration.score, 4);
+            assert_eq!(result.best, vec!["NASTIER"]);
+            assert_eq!(result.best_score, 7);
+        }
+
+        assert_eq!(episode.stage(), Stage::Conundrum);
+
+        // No conundrum to answer yet
+        assert!(episode
+            .answer_conundrum(Some("countdown"), Duration::ZERO)
+            .is_none());
+
+        let generator = ConundrumGenerator::new(&dictionary);
+        let conundrum = episode.new_conundrum(&generator).unwrap();
+
+        assert_eq!(conundrum.answer, "COUNTDOWN");
+
+        let result = episode
+            .answer_conundrum(Some("countdown"), Duration::from_secs(3))
+            .unwrap();
+
+        assert!(result.is_solved());
+        assert_eq!(result.score, CONUNDRUM_SCORE);
+
+        assert_eq!(episode.stage(), Stage::Finished);
+        assert_eq!(episode.score(), 4 + 4 + 10);
+        assert_eq!(episode.max_score(), 7 + 7 + 10);
+
+        // The episode finishes if no conundrum can be made
+        let dictionary =
+            Dictionary::new_from_string("rat\ntar", Default::default(), false).unwrap();
+
+        let mut episode = Episode::new(&dictionary, PileFrequencies::countdown(), 0, 1);
+
+        assert_eq!(episode.stage(), Stage::Conundrum);
+        assert!(episode
+            .new_conundrum(&ConundrumGenerator::new(&dictionary))
+            .is_none());
+        assert_eq!(episode.stage(), Stage::Finished);
+    }
+}
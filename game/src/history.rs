@@ -0,0 +1,374 @@
+//! Round history
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::daily::Date;
+use crate::round::{Declaration, Round};
+
+/// Record of a player's word in a round
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Time the round was played (seconds since 1970-01-01 UTC)
+    pub time: u64,
+    /// How the round was played, eg. "play" or "daily"
+    pub mode: String,
+    /// Player name, if more than one person played
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub player: Option<String>,
+    /// The letters
+    pub letters: String,
+    /// The word declared (empty if none)
+    pub word: String,
+    /// Whether the word was allowed
+    pub valid: bool,
+    /// The longest words available
+    pub best: Vec<String>,
+    /// Seconds taken to find the word
+    pub seconds: f64,
+    /// Score for the round
+    pub score: u32,
+}
+
+impl HistoryEntry {
+    /// Creates a history entry for a declaration in a round, timed now
+    pub fn new(mode: &str, round: &Round, declaration: &Declaration, taken: Duration) -> Self {
+        Self {
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            mode: mode.to_string(),
+            player: None,
+            letters: round.letters().to_string(),
+            word: declaration.word.clone(),
+            valid: declaration.is_valid(),
+            best: round.best_words(),
+            seconds: taken.as_secs_f64(),
+            score: declaration.score,
+        }
+    }
+
+    /// Returns the length of the best word available
+    pub fn best_len(&self) -> usize {
+        self.best.first().map_or(0, |w| w.len())
+    }
+
+    /// Returns the length of the word declared, or zero if it wasn't allowed
+    pub fn word_len(&self) -> usize {
+        if self.valid {
+            self.word.len()
+        } else {
+            0
+        }
+    }
+
+    /// Returns how many letters shorter the word declared was than the best word
+    pub fn gap(&self) -> usize {
+        self.best_len().saturating_sub(self.word_len())
+    }
+
+    /// Returns the date the round was played
+    pub fn date(&self) -> Date {
+        Date::from_days((self.time / 86_400) as i64)
+    }
+}
+
+/// A history file holding one JSON history entry per line
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    /// Creates a history stored in a file
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// Returns the path of the history file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Adds an entry to the end of the history file, creating it if necessary
+    pub fn append(&self, entry: &HistoryEntry) -> io::Result<()> {
+        let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(file, "{line}")
+    }
+
+    /// Loads all of the entries from the history file. A missing file has no entries
+    pub fn load(&self) -> io::Result<Vec<HistoryEntry>> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => Err(e)?,
+        };
+
+        let mut entries = Vec::new();
+
+        for (i, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry = serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} line {}: {e}", self.path.display(), i + 1),
+                )
+            })?;
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Periods to group history statistics by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    /// Calendar day
+    Day,
+    /// Week starting on Monday
+    Week,
+    /// Calendar month
+    Month,
+}
+
+impl Period {
+    /// Returns the label for the period containing a date
+    pub fn label(&self, date: Date) -> String {
+        match self {
+            Period::Day => date.to_string(),
+            Period::Week => {
+                // 1970-01-01 was a Thursday
+                let days = date.days();
+                Date::from_days(days - (days + 3).rem_euclid(7)).to_string()
+            }
+            Period::Month => format!("{:04}-{:02}", date.year, date.month),
+        }
+    }
+}
+
+/// Statistics for a set of rounds
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RoundStats {
+    /// Label for the rounds, eg. the period
+    pub label: String,
+    /// Number of rounds
+    pub rounds: usize,
+    /// Number of rounds with a valid word
+    pub valid: usize,
+    /// Number of rounds where a best length word was found
+    pub found_best: usize,
+    /// Total letters short of the best word
+    pub total_gap: usize,
+    /// Total score
+    pub total_score: u64,
+}
+
+impl RoundStats {
+    fn add(&mut self, entry: &HistoryEntry) {
+        self.rounds += 1;
+        self.valid += usize::from(entry.valid);
+        self.found_best += usize::from(entry.best_len() > 0 && entry.gap() == 0);
+        self.total_gap += entry.gap();
+        self.total_score += u64::from(entry.score);
+    }
+
+    /// Returns the proportion of rounds where a best length word was found
+    pub fn accuracy(&self) -> f64 {
+        self.proportion(self.found_best)
+    }
+
+    /// Returns the proportion of rounds with a valid word
+    pub fn valid_rate(&self) -> f64 {
+        self.proportion(self.valid)
+    }
+
+    /// Returns the mean number of letters short of the best word
+    pub fn mean_gap(&self) -> f64 {
+        self.proportion(self.total_gap)
+    }
+
+    /// Returns the mean score
+    pub fn mean_score(&self) -> f64 {
+        self.proportion(self.total_score as usize)
+    }
+
+    fn proportion(&self, value: usize) -> f64 {
+        if self.rounds == 0 {
+            0.0
+        } else {
+            value as f64 / self.rounds as f64
+        }
+    }
+}
+
+/// Statistics for a history
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryStats {
+    /// Statistics for all rounds
+    pub overall: RoundStats,
+    /// Statistics for each period in date order
+    pub periods: Vec<RoundStats>,
+    /// Best words missed and the number of times each was missed, most missed first then
+    /// alphabetical
+    pub missed: Vec<(String, usize)>,
+}
+
+/// Calculates statistics for history entries grouped by period
+pub fn history_stats(entries: &[HistoryEntry], period: Period) -> HistoryStats {
+    let mut stats = HistoryStats {
+        overall: RoundStats {
+            label: "All".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut missed = HashMap::new();
+
+    let mut entries = entries.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|e| e.time);
+
+    for entry in entries {
+        stats.overall.add(entry);
+
+        let label = period.label(entry.date());
+
+        match stats.periods.last_mut() {
+            Some(last) if last.label == label => last.add(entry),
+            _ => {
+                let mut period_stats = RoundStats {
+                    label,
+                    ..Default::default()
+                };
+
+                period_stats.add(entry);
+                stats.periods.push(period_stats);
+            }
+        }
+
+        if entry.gap() > 0 {
+            for word in &entry.best {
+                *missed.entry(word.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    stats.missed = missed.into_iter().collect();
+    stats
+        .missed
+        .sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use dictionary::Dictionary;
+
+    use super::*;
+
+    fn entry(day: u64, word: &str, valid: bool, best: &[&str]) -> HistoryEntry {
+        HistoryEntry {
+            time: day * 86_400 + 3600,
+            mode: "play".into(),
+            player: None,
+            letters: "RUSTAEINT".into(),
+            word: word.into(),
+            valid,
+            best: best.iter().map(|w| w.to_string()).collect(),
+            seconds: 10.0,
+            score: if valid { word.len() as u32 } else { 0 },
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let entries = vec![
+            entry(2, "TRUST", true, &["TRUSTY"]),
+            entry(0, "STAIN", true, &["STAIN", "SATIN"]),
+            entry(1, "XYZ", false, &["TRUSTY"]),
+            entry(8, "RUST", true, &["STAINER", "NASTIER"]),
+        ];
+
+        let stats = history_stats(&entries, Period::Week);
+
+        assert_eq!(stats.overall.rounds, 4);
+        assert_eq!(stats.overall.valid, 3);
+        assert_eq!(stats.overall.found_best, 1);
+        assert_eq!(stats.overall.mean_gap(), (1 + 6 + 3) as f64 / 4.0);
+
+        // 1970-01-05 was a Monday
+        assert_eq!(
+            stats.periods.iter().map(|p| &p.label).collect::<Vec<_>>(),
+            vec!["1969-12-29", "1970-01-05"]
+        );
+        assert_eq!(stats.periods[0].rounds, 3);
+        assert_eq!(stats.periods[0].accuracy(), 1.0 / 3.0);
+
+        assert_eq!(
+            stats.missed,
+            vec![
+                ("TRUSTY".to_string(), 2),
+                ("NASTIER".to_string(), 1),
+                ("STAINER".to_string(), 1)
+            ]
+        );
+
+        assert_eq!(Period::Month.label(entries[3].date()), "1970-01");
+        assert_eq!(Period::Day.label(entries[3].date()), "1970-01-09");
+    }
+
+    #[test]
+    fn file() {
+        let path = env::temp_dir().join(format!("countdown_history_{}.jsonl", std::process::id()));
+        let history = History::new(&path);
+
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(history.load().unwrap(), vec![]);
+
+        let dictionary =
+            Dictionary::new_from_string("rust\ntrust", Default::default(), false).unwrap();
+
+        let mut round = Round::new(&dictionary);
+        round.set_letters("RUSTAEINT");
+
+        let entry = HistoryEntry::new(
+            "play",
+            &round,
+            &round.declare("rust"),
+            Duration::from_secs(5),
+        );
+
+        assert_eq!(entry.best, vec!["TRUST"]);
+        assert_eq!(entry.gap(), 1);
+
+        history.append(&entry).unwrap();
+        history.append(&entry).unwrap();
+
+        assert_eq!(history.load().unwrap(), vec![entry.clone(), entry]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
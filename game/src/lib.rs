@@ -0,0 +1,21 @@
+#![warn(missing_docs)]
+
+//! Countdown letters game engine
+
+mod daily;
+mod drill;
+mod episode;
+mod history;
+mod round;
+mod two_player;
+
+pub use crate::daily::{Daily, DailyRules, Date, DateError};
+pub use crate::drill::{missed_words, scramble, Card, DrillSchedule, Grade};
+pub use crate::episode::{
+    ConundrumResult, Episode, LettersResult, Stage, CONUNDRUM_SCORE, EPISODE_ROUNDS,
+};
+pub use crate::history::{history_stats, History, HistoryEntry, HistoryStats, Period, RoundStats};
+pub use crate::round::{Declaration, Round, RoundError, ROUND_LETTERS, ROUND_TIME};
+pub use crate::two_player::{
+    adjudicate, Entry, EntryResult, GameRecord, Player, RoundRecord, TwoPlayerGame,
+};
@@ -0,0 +1,236 @@
+//! Letters round
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use dictionary::Dictionary;
+use solver::{
+    check_word, find_top_words, find_words, is_vowel, CountdownScorer, DrawError, LetterPiles,
+    LetterRules, PileKind, Rng, Scorer, SolverArgs, WordError,
+};
+
+/// Number of letters in a round
+pub const ROUND_LETTERS: usize = 9;
+
+/// Time allowed to find a word
+pub const ROUND_TIME: Duration = Duration::from_secs(30);
+
+/// A letters round. Letters are chosen one at a time from the vowel and consonant piles
+/// following the Countdown rules, then words are declared and scored
+pub struct Round<'a> {
+    dictionary: &'a Dictionary,
+    rules: LetterRules,
+    letters: String,
+}
+
+impl<'a> Round<'a> {
+    /// Creates a new round with no letters chosen
+    pub fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            dictionary,
+            rules: LetterRules::countdown(),
+            letters: String::with_capacity(ROUND_LETTERS),
+        }
+    }
+
+    /// Returns the letters chosen so far
+    pub fn letters(&self) -> &str {
+        &self.letters
+    }
+
+    /// Returns true when all of the letters have been chosen
+    pub fn is_complete(&self) -> bool {
+        self.letters.len() >= ROUND_LETTERS
+    }
+
+    /// Returns true if choosing from the pile still allows the minimum numbers of vowels and
+    /// consonants to be reached
+    pub fn can_choose(&self, kind: PileKind) -> bool {
+        if self.is_complete() {
+            return false;
+        }
+
+        let vowels = self.letters.chars().filter(|c| is_vowel(*c)).count()
+            + usize::from(kind == PileKind::Vowel);
+        let consonants = self.letters.len() + 1 - vowels;
+
+        let needed = self.rules.min_vowels.saturating_sub(vowels)
+            + self.rules.min_consonants.saturating_sub(consonants);
+
+        needed < ROUND_LETTERS - self.letters.len()
+    }
+
+    /// Chooses the next letter from one of the piles
+    pub fn choose(
+        &mut self,
+        kind: PileKind,
+        piles: &mut LetterPiles,
+        rng: &mut Rng,
+    ) -> Result<char, RoundError> {
+        if self.is_complete() {
+            Err(RoundError::Complete)?;
+        }
+
+        if !self.can_choose(kind) {
+            Err(RoundError::ChoiceNotAllowed(kind))?;
+        }
+
+        let letter = piles.draw(kind, rng).map_err(RoundError::Draw)?;
+
+        self.letters.push(letter);
+
+        Ok(letter)
+    }
+
+    /// Sets all of the letters for the round (upper case A-Z)
+    pub fn set_letters(&mut self, letters: &str) {
+        self.letters = letters.to_string();
+    }
+
+    /// Checks and scores a declared word. An empty word scores nothing
+    pub fn declare(&self, word: &str) -> Declaration {
+        let result = check_word(word, &self.letters, self.dictionary, 1);
+
+        let score = match &result {
+            Ok(word) => CountdownScorer.score(word),
+            Err(_) => 0,
+        };
+
+        Declaration {
+            word: word.to_ascii_uppercase(),
+            result: result.map(|_| ()),
+            score,
+        }
+    }
+
+    /// Returns all of the words which can be made from the letters in alphabetical order
+    pub fn words(&self) -> Vec<String> {
+        find_words(SolverArgs::new(&self.letters, self.dictionary))
+    }
+
+    /// Returns all of the longest words which can be made from the letters in alphabetical order
+    pub fn best_words(&self) -> Vec<String> {
+        let words = self.words();
+
+        let best = words.iter().map(|w| w.len()).max().unwrap_or(0);
+
+        words.into_iter().filter(|w| w.len() == best).collect()
+    }
+
+    /// Returns the best words which can be made from the letters, longest first
+    pub fn top_words(&self, count: usize) -> Vec<String> {
+        find_top_words(SolverArgs::new(&self.letters, self.dictionary), count)
+    }
+}
+
+/// A declared word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declaration {
+    /// The word declared (upper case)
+    pub word: String,
+    /// Whether the word is allowed
+    pub result: Result<(), WordError>,
+    /// Score for the word
+    pub score: u32,
+}
+
+impl Declaration {
+    /// Returns true if the word is allowed
+    pub fn is_valid(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Round errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundError {
+    /// All of the letters have been chosen
+    Complete,
+    /// Choosing from the pile would not leave enough choices for the minimum vowels or
+    /// consonants
+    ChoiceNotAllowed(PileKind),
+    /// The letter could not be drawn
+    Draw(DrawError),
+}
+
+impl fmt::Display for RoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundError::Complete => write!(f, "All of the letters have been chosen"),
+            RoundError::ChoiceNotAllowed(kind) => {
+                let other = match kind {
+                    PileKind::Vowel => "consonants",
+                    PileKind::Consonant => "vowels",
+                };
+
+                write!(
+                    f,
+                    "Another {} can't be chosen, more {other} are needed",
+                    kind.name()
+                )
+            }
+            RoundError::Draw(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for RoundError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round() {
+        let dictionary =
+            Dictionary::new_from_string("rust\nrut\nruts\ntrust", Default::default(), false)
+                .unwrap();
+
+        let mut round = Round::new(&dictionary);
+        let mut piles = LetterPiles::countdown();
+        let mut rng = Rng::new(1);
+
+        // Six consonants then no more allowed
+        for _ in 0..6 {
+            round
+                .choose(PileKind::Consonant, &mut piles, &mut rng)
+                .unwrap();
+        }
+
+        assert!(!round.can_choose(PileKind::Consonant));
+        assert_eq!(
+            round.choose(PileKind::Consonant, &mut piles, &mut rng),
+            Err(RoundError::ChoiceNotAllowed(PileKind::Consonant))
+        );
+
+        for _ in 0..3 {
+            round.choose(PileKind::Vowel, &mut piles, &mut rng).unwrap();
+        }
+
+        assert!(round.is_complete());
+        assert_eq!(round.letters().len(), ROUND_LETTERS);
+        assert_eq!(
+            round.choose(PileKind::Vowel, &mut piles, &mut rng),
+            Err(RoundError::Complete)
+        );
+
+        // Declarations
+        round.set_letters("RUSTAEIOT");
+
+        assert_eq!(
+            round.declare("trust"),
+            Declaration {
+                word: "TRUST".into(),
+                result: Ok(()),
+                score: 5,
+            }
+        );
+        assert_eq!(round.declare("tsar").score, 0);
+        assert!(!round.declare("").is_valid());
+
+        assert_eq!(round.top_words(1), vec!["TRUST"]);
+        assert_eq!(round.best_words(), vec!["TRUST"]);
+        assert_eq!(round.words(), vec!["RUST", "RUT", "RUTS", "TRUST"]);
+    }
+}
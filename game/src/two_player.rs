@@ -0,0 +1,274 @@
+//! Two player game
+
+use dictionary::Dictionary;
+use serde::Serialize;
+
+use crate::round::Round;
+
+/// A player and their running score
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Player {
+    /// Player name
+    pub name: String,
+    /// Total score
+    pub score: u32,
+}
+
+/// A player's declaration at the end of a round
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Declared word length
+    pub declared: usize,
+    /// The word (empty if no word was found)
+    pub word: String,
+}
+
+impl Entry {
+    /// Creates an entry
+    pub fn new(declared: usize, word: &str) -> Self {
+        Self {
+            declared,
+            word: word.to_string(),
+        }
+    }
+}
+
+/// The adjudicated result of a player's entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryResult {
+    /// Declared word length
+    pub declared: usize,
+    /// The word (upper case)
+    pub word: String,
+    /// Whether the word is allowed
+    pub valid: bool,
+    /// Reason the word is not allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Score for the round
+    pub score: u32,
+}
+
+/// Record of a played round
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RoundRecord {
+    /// Round number (1 based)
+    pub number: usize,
+    /// Index of the player who chose the letters
+    pub chooser: usize,
+    /// The letters
+    pub letters: String,
+    /// Result for each player
+    pub entries: Vec<EntryResult>,
+    /// The best words available
+    pub best: Vec<String>,
+}
+
+/// Record of a whole game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameRecord {
+    /// The players and their final scores
+    pub players: Vec<Player>,
+    /// The rounds played
+    pub rounds: Vec<RoundRecord>,
+}
+
+/// A game between two players. The players take turns to choose the letters, then both declare
+/// the length of their word. The longest valid word scores, and both players score on a tie
+pub struct TwoPlayerGame<'a> {
+    dictionary: &'a Dictionary,
+    record: GameRecord,
+}
+
+impl<'a> TwoPlayerGame<'a> {
+    /// Creates a new game
+    pub fn new(dictionary: &'a Dictionary, names: [&str; 2]) -> Self {
+        let players = names
+            .iter()
+            .map(|name| Player {
+                name: name.to_string(),
+                score: 0,
+            })
+            .collect();
+
+        Self {
+            dictionary,
+            record: GameRecord {
+                players,
+                rounds: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns the players
+    pub fn players(&self) -> &[Player] {
+        &self.record.players
+    }
+
+    /// Returns the index of the player choosing the letters for the next round
+    pub fn chooser(&self) -> usize {
+        self.record.rounds.len() % 2
+    }
+
+    /// Starts the next round
+    pub fn new_round(&self) -> Round<'a> {
+        Round::new(self.dictionary)
+    }
+
+    /// Adjudicates a finished round, updates the scores and returns the round record
+    pub fn finish_round(&mut self, round: &Round, entries: [Entry; 2]) -> &RoundRecord {
+        let entries = adjudicate(round, &entries);
+
+        for (player, entry) in self.record.players.iter_mut().zip(&entries) {
+            player.score += entry.score;
+        }
+
+        let record = RoundRecord {
+            number: self.record.rounds.len() + 1,
+            chooser: self.chooser(),
+            letters: round.letters().to_string(),
+            entries: entries.to_vec(),
+            best: round.best_words(),
+        };
+
+        self.record.rounds.push(record);
+
+        self.record.rounds.last().unwrap()
+    }
+
+    /// Returns the indexes of the leading players (both on a tie)
+    pub fn leaders(&self) -> Vec<usize> {
+        let best = self.record.players.iter().map(|p| p.score).max();
+
+        self.record
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| Some(p.score) == best)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns the game record
+    pub fn record(&self) -> &GameRecord {
+        &self.record
+    }
+}
+
+/// Adjudicates the players' entries for a round. A word is only valid if it is allowed and has the
+/// declared length. The longest valid word scores, and all valid words of that length score
+pub fn adjudicate(round: &Round, entries: &[Entry; 2]) -> [EntryResult; 2] {
+    let mut results = entries.clone().map(|entry| {
+        let declaration = round.declare(&entry.word);
+
+        let reason = if entry.word.is_empty() {
+            Some("No word declared".to_string())
+        } else if let Err(e) = &declaration.result {
+            Some(e.to_string())
+        } else if entry.word.len() != entry.declared {
+            Some(format!(
+                "Declared {} letters but the word has {}",
+                entry.declared,
+                entry.word.len()
+            ))
+        } else {
+            None
+        };
+
+        EntryResult {
+            declared: entry.declared,
+            word: declaration.word,
+            valid: reason.is_none(),
+            reason,
+            score: declaration.score,
+        }
+    });
+
+    let best = results
+        .iter()
+        .filter(|r| r.valid)
+        .map(|r| r.word.len())
+        .max();
+
+    for result in &mut results {
+        if !result.valid || Some(result.word.len()) != best {
+            result.score = 0;
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjudication() {
+        let dictionary = Dictionary::new_from_string(
+            "rust\nrut\nruts\ntrust\nstrut\nsaturnite",
+            Default::default(),
+            false,
+        )
+        .unwrap();
+
+        let mut game = TwoPlayerGame::new(&dictionary, ["Ann", "Bob"]);
+
+        // Longest valid word scores
+        let mut round = game.new_round();
+        round.set_letters("RUSTTAEIN");
+
+        assert_eq!(game.chooser(), 0);
+
+        let record = game.finish_round(&round, [Entry::new(4, "rust"), Entry::new(5, "trust")]);
+
+        assert_eq!(record.number, 1);
+        assert_eq!(record.chooser, 0);
+        assert_eq!(record.best, vec!["SATURNITE"]);
+        assert_eq!(record.entries[0].score, 0);
+        assert!(record.entries[0].valid);
+        assert_eq!(record.entries[1].score, 5);
+
+        // Ties both score, invalid words don't
+        round.set_letters("RUSTTAEIN");
+
+        assert_eq!(game.chooser(), 1);
+
+        let record = game.finish_round(&round, [Entry::new(5, "strut"), Entry::new(5, "trust")]);
+
+        assert_eq!(record.entries[0].score, 5);
+        assert_eq!(record.entries[1].score, 5);
+
+        let record = game.finish_round(&round, [Entry::new(9, "satunrite"), Entry::new(3, "rut")]);
+
+        assert!(!record.entries[0].valid);
+        assert_eq!(record.entries[1].score, 3);
+
+        // A nine scores 18
+        let record = game.finish_round(&round, [Entry::new(4, "ruts"), Entry::new(9, "saturnite")]);
+
+        assert!(record.entries[0].valid);
+        assert_eq!(record.entries[0].score, 0);
+        assert_eq!(record.entries[1].score, 18);
+
+        // Wrong declared length is invalid
+
+        let record = game.finish_round(&round, [Entry::new(5, "ruts"), Entry::new(0, "")]);
+
+        assert_eq!(
+            record.entries[0].reason.as_deref(),
+            Some("Declared 5 letters but the word has 4")
+        );
+        assert_eq!(
+            record.entries[1].reason.as_deref(),
+            Some("No word declared")
+        );
+
+        assert_eq!(
+            game.players().iter().map(|p| p.score).collect::<Vec<_>>(),
+            vec![5, 31]
+        );
+        assert_eq!(game.leaders(), vec![1]);
+        assert_eq!(game.record().rounds.len(), 5);
+    }
+}
@@ -0,0 +1,258 @@
+//! Arithmetic expressions
+
+use std::cmp::Reverse;
+use std::fmt;
+
+/// Arithmetic operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// Addition
+    Add,
+    /// Subtraction
+    Sub,
+    /// Multiplication
+    Mul,
+    /// Division
+    Div,
+}
+
+impl Op {
+    /// All of the operations
+    pub const ALL: [Op; 4] = [Op::Add, Op::Sub, Op::Mul, Op::Div];
+
+    /// Returns the symbol for the operation
+    pub fn symbol(&self) -> char {
+        match self {
+            Op::Add => '+',
+            Op::Sub => '-',
+            Op::Mul => '×',
+            Op::Div => '÷',
+        }
+    }
+
+    /// Applies the operation following the Countdown rules. Returns None if the result would not
+    /// be a positive integer, or if the operation can't help (multiplying or dividing by one).
+    /// Addition and multiplication are only applied with the larger operand first
+    pub fn apply(&self, left: u32, right: u32) -> Option<u32> {
+        match self {
+            Op::Add => (left >= right).then(|| left.checked_add(right))?,
+            Op::Sub => (left > right).then(|| left - right),
+            Op::Mul => (left >= right && right > 1).then(|| left.checked_mul(right))?,
+            Op::Div => (right > 1 && left.is_multiple_of(right)).then(|| left / right),
+        }
+    }
+
+    fn is_additive(&self) -> bool {
+        matches!(self, Op::Add | Op::Sub)
+    }
+}
+
+/// An arithmetic expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// A number
+    Number(u32),
+    /// An operation on two expressions
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Returns the value of the expression, or None if a step overflows or doesn't give a
+    /// positive integer
+    pub fn value(&self) -> Option<u32> {
+        match self {
+            Expr::Number(n) => Some(*n),
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (left.value()?, right.value()?);
+
+                match op {
+                    Op::Add => left.checked_add(right),
+                    Op::Sub => left.checked_sub(right).filter(|v| *v > 0),
+                    Op::Mul => left.checked_mul(right),
+                    Op::Div => (right > 0 && left.is_multiple_of(right)).then(|| left / right),
+                }
+            }
+        }
+    }
+
+    /// Returns the numbers used by the expression
+    pub fn numbers(&self) -> Vec<u32> {
+        match self {
+            Expr::Number(n) => vec![*n],
+            Expr::Binary(_, left, right) => {
+                let mut numbers = left.numbers();
+                numbers.extend(right.numbers());
+                numbers
+            }
+        }
+    }
+
+    /// Renders the expression with operands of + and - (and of × and ÷) grouped and ordered
+    /// largest first so equivalent expressions render the same
+    fn render(&self) -> Rendered {
+        match self {
+            Expr::Number(n) => Rendered {
+                text: n.to_string(),
+                value: *n,
+                level: Level::Atom,
+            },
+            Expr::Binary(op, _, _) => {
+                let additive = op.is_additive();
+
+                let mut positive = Vec::new();
+                let mut negative = Vec::new();
+
+                self.collect(additive, true, &mut positive, &mut negative);
+
+                let (join, invert) = if additive {
+                    (" + ", " - ")
+                } else {
+                    (" × ", " ÷ ")
+                };
+
+                let operand = |r: Rendered| {
+                    if !additive && r.level == Level::Add {
+                        format!("({})", r.text)
+                    } else {
+                        r.text
+                    }
+                };
+
+                for terms in [&mut positive, &mut negative] {
+                    terms.sort_by_key(|r| (Reverse(r.value), r.text.clone()));
+                }
+
+                let mut text = positive
+                    .into_iter()
+                    .map(operand)
+                    .collect::<Vec<_>>()
+                    .join(join);
+
+                for r in negative {
+                    text.push_str(invert);
+                    text.push_str(&operand(r));
+                }
+
+                Rendered {
+                    text,
+                    value: self.value().unwrap_or(0),
+                    level: if additive { Level::Add } else { Level::Mul },
+                }
+            }
+        }
+    }
+
+    /// Collects the operands of a group of additive or multiplicative operations
+    fn collect(
+        &self,
+        additive: bool,
+        positive: bool,
+        positives: &mut Vec<Rendered>,
+        negatives: &mut Vec<Rendered>,
+    ) {
+        match self {
+            Expr::Binary(op, left, right) if op.is_additive() == additive => {
+                let invert = matches!(op, Op::Sub | Op::Div);
+
+                left.collect(additive, positive, positives, negatives);
+                right.collect(additive, positive != invert, positives, negatives);
+            }
+            _ => {
+                let rendered = self.render();
+
+                if positive {
+                    positives.push(rendered);
+                } else {
+                    negatives.push(rendered);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render().text)
+    }
+}
+
+/// Operator precedence level of a rendered expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Atom,
+    Add,
+    Mul,
+}
+
+struct Rendered {
+    text: String,
+    value: u32,
+    level: Level,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bin(op: Op, left: Expr, right: Expr) -> Expr {
+        Expr::Binary(op, Box::new(left), Box::new(right))
+    }
+
+    fn num(n: u32) -> Expr {
+        Expr::Number(n)
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(Op::Add.apply(3, 2), Some(5));
+        assert_eq!(Op::Add.apply(2, 3), None);
+        assert_eq!(Op::Sub.apply(3, 3), None);
+        assert_eq!(Op::Mul.apply(3, 1), None);
+        assert_eq!(Op::Div.apply(9, 3), Some(3));
+        assert_eq!(Op::Div.apply(9, 2), None);
+        assert_eq!(Op::Mul.apply(u32::MAX, 2), None);
+    }
+
+    #[test]
+    fn display() {
+        // (100 + 3) × 8 - 12
+        let expr = bin(
+            Op::Sub,
+            bin(Op::Mul, bin(Op::Add, num(3), num(100)), num(8)),
+            num(12),
+        );
+
+        assert_eq!(expr.value(), Some(812));
+        assert_eq!(expr.to_string(), "(100 + 3) × 8 - 12");
+        assert_eq!(expr.numbers(), vec![3, 100, 8, 12]);
+
+        // 75 - (25 - 6) = 75 + 6 - 25
+        let expr = bin(Op::Sub, num(75), bin(Op::Sub, num(25), num(6)));
+
+        assert_eq!(expr.to_string(), "75 + 6 - 25");
+
+        // Equivalent groupings render the same
+        let a = bin(Op::Mul, num(2), bin(Op::Mul, num(8), num(3)));
+        let b = bin(Op::Mul, bin(Op::Mul, num(3), num(2)), num(8));
+
+        assert_eq!(a.to_string(), "8 × 3 × 2");
+        assert_eq!(a.to_string(), b.to_string());
+
+        // Divisors
+        let expr = bin(Op::Div, num(100), bin(Op::Div, num(10), num(5)));
+
+        assert_eq!(expr.value(), Some(50));
+        assert_eq!(expr.to_string(), "100 × 5 ÷ 10");
+
+        let expr = bin(Op::Div, num(50), bin(Op::Sub, num(7), num(2)));
+
+        assert_eq!(expr.to_string(), "50 ÷ (7 - 2)");
+
+        // Invalid expressions have no value
+        assert_eq!(bin(Op::Sub, num(2), num(7)).value(), None);
+        assert_eq!(bin(Op::Sub, num(7), num(7)).value(), None);
+        assert_eq!(bin(Op::Div, num(7), num(2)).value(), None);
+        assert_eq!(bin(Op::Div, num(7), num(0)).value(), None);
+        assert_eq!(bin(Op::Mul, num(u32::MAX), num(2)).value(), None);
+    }
+}
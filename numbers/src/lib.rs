@@ -0,0 +1,11 @@
+#![warn(missing_docs)]
+
+//! Countdown numbers game solver
+
+mod expr;
+mod rules;
+mod solve;
+
+pub use crate::expr::{Expr, Op};
+pub use crate::rules::{check_countdown, NumbersError, MAX_NUMBERS, TARGET_MAX, TARGET_MIN};
+pub use crate::solve::{solve_numbers, Solution, Solutions};
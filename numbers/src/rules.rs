@@ -0,0 +1,110 @@
+//! Countdown numbers rules
+
+use std::error::Error;
+use std::fmt;
+
+/// Number of numbers chosen in a Countdown numbers round
+pub const MAX_NUMBERS: usize = 6;
+
+/// Lowest Countdown target
+pub const TARGET_MIN: u32 = 101;
+
+/// Highest Countdown target
+pub const TARGET_MAX: u32 = 999;
+
+/// Large numbers (one of each)
+const LARGE: [u32; 4] = [25, 50, 75, 100];
+
+/// Number of each small number (1 to 10)
+const SMALL_COUNT: usize = 2;
+
+/// Numbers game errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumbersError {
+    /// The wrong number of numbers was given
+    WrongCount(usize),
+    /// A number is not one of the Countdown numbers
+    InvalidNumber(u32),
+    /// A number is used more times than it appears in the Countdown numbers
+    TooMany(u32),
+    /// The target is out of range
+    InvalidTarget(u32),
+}
+
+impl fmt::Display for NumbersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumbersError::WrongCount(count) => {
+                write!(f, "{MAX_NUMBERS} numbers are needed ({count} given)")
+            }
+            NumbersError::InvalidNumber(n) => write!(
+                f,
+                "{n} is not a Countdown number (1 to 10, 25, 50, 75 or 100)"
+            ),
+            NumbersError::TooMany(n) => write!(f, "{n} is used too many times"),
+            NumbersError::InvalidTarget(target) => write!(
+                f,
+                "Target {target} must be between {TARGET_MIN} and {TARGET_MAX}"
+            ),
+        }
+    }
+}
+
+impl Error for NumbersError {}
+
+/// Checks the numbers and target follow the Countdown rules: six numbers chosen from two each of
+/// 1 to 10 and one each of 25, 50, 75 and 100, and a target from 101 to 999
+pub fn check_countdown(numbers: &[u32], target: u32) -> Result<(), NumbersError> {
+    if numbers.len() != MAX_NUMBERS {
+        Err(NumbersError::WrongCount(numbers.len()))?;
+    }
+
+    for &n in numbers {
+        let allowed = match n {
+            1..=10 => SMALL_COUNT,
+            _ if LARGE.contains(&n) => 1,
+            _ => Err(NumbersError::InvalidNumber(n))?,
+        };
+
+        if numbers.iter().filter(|&&m| m == n).count() > allowed {
+            Err(NumbersError::TooMany(n))?;
+        }
+    }
+
+    if !(TARGET_MIN..=TARGET_MAX).contains(&target) {
+        Err(NumbersError::InvalidTarget(target))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules() {
+        assert_eq!(check_countdown(&[100, 75, 3, 6, 8, 2], 812), Ok(()));
+        assert_eq!(check_countdown(&[10, 10, 1, 1, 25, 50], 101), Ok(()));
+        assert_eq!(
+            check_countdown(&[100, 75, 3, 6, 8], 812),
+            Err(NumbersError::WrongCount(5))
+        );
+        assert_eq!(
+            check_countdown(&[100, 75, 3, 6, 8, 11], 812),
+            Err(NumbersError::InvalidNumber(11))
+        );
+        assert_eq!(
+            check_countdown(&[100, 100, 3, 6, 8, 2], 812),
+            Err(NumbersError::TooMany(100))
+        );
+        assert_eq!(
+            check_countdown(&[3, 3, 3, 6, 8, 2], 812),
+            Err(NumbersError::TooMany(3))
+        );
+        assert_eq!(
+            check_countdown(&[100, 75, 3, 6, 8, 2], 1000),
+            Err(NumbersError::InvalidTarget(1000))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Numbers game search
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::expr::{Expr, Op};
+
+/// A solution for a target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    /// The expression
+    pub expr: Expr,
+    /// Value of the expression
+    pub value: u32,
+}
+
+impl Solution {
+    /// Returns the number of numbers used
+    pub fn numbers_used(&self) -> usize {
+        self.expr.numbers().len()
+    }
+}
+
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.expr, self.value)
+    }
+}
+
+/// Solutions for a target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solutions {
+    /// The target
+    pub target: u32,
+    /// Distance of the closest solutions from the target (zero if exact)
+    pub distance: u32,
+    /// Distinct closest solutions, fewest numbers used first then shortest
+    pub solutions: Vec<Solution>,
+}
+
+impl Solutions {
+    /// Returns true if the target can be reached exactly
+    pub fn is_exact(&self) -> bool {
+        self.distance == 0 && !self.solutions.is_empty()
+    }
+}
+
+/// A number or intermediate result available to combine
+#[derive(Clone, Copy)]
+struct Item {
+    value: u32,
+    node: usize,
+}
+
+/// How a value was made
+enum Node {
+    Number(u32),
+    Step(Op, usize, usize),
+}
+
+struct Search {
+    target: u32,
+    nodes: Vec<Node>,
+    distance: u32,
+    seen: HashSet<String>,
+    solutions: Vec<Solution>,
+}
+
+impl Search {
+    fn search(&mut self, items: &[Item]) {
+        for i in 0..items.len() {
+            for j in 0..items.len() {
+                if i == j {
+                    continue;
+                }
+
+                let (left, right) = (items[i], items[j]);
+
+                // Only combine equal values once
+                if left.value == right.value && i > j {
+                    continue;
+                }
+
+                for op in Op::ALL {
+                    let Some(value) = op.apply(left.value, right.value) else {
+                        continue;
+                    };
+
+                    self.nodes.push(Node::Step(op, left.node, right.node));
+
+                    let node = self.nodes.len() - 1;
+
+                    self.record(value, node);
+
+                    if items.len() > 2 {
+                        let mut next = Vec::with_capacity(items.len() - 1);
+
+                        next.extend(
+                            items
+                                .iter()
+                                .enumerate()
+                                .filter(|(k, _)| *k != i && *k != j)
+                                .map(|(_, item)| *item),
+                        );
+                        next.push(Item { value, node });
+
+                        self.search(&next);
+                    }
+
+                    self.nodes.pop();
+                }
+            }
+        }
+    }
+
+    fn record(&mut self, value: u32, node: usize) {
+        let distance = value.abs_diff(self.target);
+
+        if distance > self.distance {
+            return;
+        }
+
+        if distance < self.distance {
+            self.distance = distance;
+            self.seen.clear();
+            self.solutions.clear();
+        }
+
+        let expr = self.expr(node);
+
+        if self.seen.insert(expr.to_string()) {
+            self.solutions.push(Solution { expr, value });
+        }
+    }
+
+    fn expr(&self, node: usize) -> Expr {
+        match self.nodes[node] {
+            Node::Number(n) => Expr::Number(n),
+            Node::Step(op, left, right) => {
+                Expr::Binary(op, Box::new(self.expr(left)), Box::new(self.expr(right)))
+            }
+        }
+    }
+}
+
+/// Finds the solutions closest to the target using each number at most once and +, -, × and ÷.
+/// Every intermediate result must be a positive integer
+pub fn solve_numbers(numbers: &[u32], target: u32) -> Solutions {
+    let mut search = Search {
+        target,
+        nodes: Vec::new(),
+        distance: u32::MAX,
+        seen: HashSet::new(),
+        solutions: Vec::new(),
+    };
+
+    let items = numbers
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            search.nodes.push(Node::Number(n));
+
+            Item {
+                value: n,
+                node: search.nodes.len() - 1,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for item in &items {
+        search.record(item.value, item.node);
+    }
+
+    search.search(&items);
+
+    let mut solutions = search.solutions;
+
+    solutions.sort_by_cached_key(|s| {
+        let text = s.expr.to_string();
+        (s.numbers_used(), text.chars().count(), text)
+    });
+
+    Solutions {
+        target,
+        distance: if solutions.is_empty() {
+            0
+        } else {
+            search.distance
+        },
+        solutions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(solutions: &Solutions) -> Vec<String> {
+        solutions.solutions.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn exact() {
+        let solutions = solve_numbers(&[100, 3, 8, 12], 812);
+
+        assert!(solutions.is_exact());
+        assert_eq!(
+            texts(&solutions),
+            vec!["100 × 8 + 12 = 812", "(100 + 3) × 8 - 12 = 812"]
+        );
+
+        // A number on its own
+        let solutions = solve_numbers(&[100, 3, 8], 100);
+
+        assert_eq!(texts(&solutions)[0], "100 = 100");
+    }
+
+    #[test]
+    fn closest() {
+        let solutions = solve_numbers(&[2, 3], 7);
+
+        assert_eq!(solutions.distance, 1);
+        assert_eq!(texts(&solutions), vec!["3 × 2 = 6"]);
+
+        let solutions = solve_numbers(&[5, 2], 9);
+
+        assert_eq!(solutions.distance, 1);
+        assert_eq!(texts(&solutions), vec!["5 × 2 = 10"]);
+
+        let solutions = solve_numbers(&[7, 1], 7);
+
+        // Multiplying or dividing by one isn't needed
+        assert_eq!(texts(&solutions), vec!["7 = 7"]);
+
+        assert_eq!(solve_numbers(&[], 7).solutions, vec![]);
+    }
+
+    #[test]
+    fn rules() {
+        // 3 / 2 isn't an integer, 2 - 3 isn't positive, and each number can only be used once
+        let solutions = solve_numbers(&[3, 2, 4], 1);
+
+        assert!(solutions.is_exact());
+
+        for solution in &solutions.solutions {
+            let mut numbers = solution.expr.numbers();
+            numbers.sort();
+            numbers.dedup();
+            assert_eq!(numbers.len(), solution.numbers_used());
+        }
+
+        assert_eq!(texts(&solve_numbers(&[3, 3], 9)), vec!["3 × 3 = 9"]);
+        assert_eq!(solve_numbers(&[3], 9).distance, 6);
+    }
+
+    #[test]
+    fn countdown() {
+        let solutions = solve_numbers(&[75, 50, 2, 3, 8, 7], 812);
+
+        assert!(solutions.is_exact());
+        assert!(solutions.solutions.iter().all(|s| s.value == 812));
+        assert!(solutions
+            .solutions
+            .iter()
+            .all(|s| s.expr.value() == Some(812)));
+    }
+}
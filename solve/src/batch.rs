@@ -0,0 +1,136 @@
+//! Batch solving
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::time::Instant;
+
+use clap::ValueEnum;
+use dictionary::{Dictionary, WordSizeConstraint};
+use numformat::NumFormat;
+use serde_json::json;
+use solver::{normalise_letters, solve_batch, BatchArgs, BatchResult};
+
+use crate::{letter_rules, signature_index, solver_backend, Args};
+
+/// Batch output formats
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum BatchFormat {
+    /// One line of text per letter set
+    #[default]
+    Text,
+    /// One JSON object per letter set (JSON lines)
+    Json,
+}
+
+/// Solves each line of a file (or stdin for '-') as a letter set
+pub fn batch(args: &Args, file: &str) -> io::Result<()> {
+    // Read the letter sets
+    let lines = if file == "-" {
+        io::stdin().lock().lines().collect::<io::Result<Vec<_>>>()?
+    } else {
+        BufReader::new(File::open(file)?)
+            .lines()
+            .collect::<io::Result<Vec<_>>>()?
+    };
+
+    // Keep diagnostics out of the JSON record stream
+    let verbose = args.verbose && matches!(args.format, BatchFormat::Text);
+
+    let rules = letter_rules(args.countdown);
+
+    // Load words up to the longest letter set
+    let mut size = WordSizeConstraint::default();
+
+    size.set_min(args.min_len as usize);
+
+    if !args.reuse_letters {
+        let max_len = lines
+            .iter()
+            .filter_map(|line| normalise_letters(line, &rules).ok())
+            .map(|letters| letters.len())
+            .max()
+            .unwrap_or(0);
+
+        size.set_max(max_len);
+    }
+
+    let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, verbose)?;
+
+    let index = signature_index(args, &dictionary, verbose);
+
+    // Solve
+    let start_time = Instant::now();
+
+    let outcome = solve_batch(
+        &lines,
+        &BatchArgs {
+            rules,
+            reuse_letters: args.reuse_letters,
+            threads: args.threads.unwrap_or(0),
+            backend: solver_backend(&index),
+            ..BatchArgs::new(&dictionary)
+        },
+    );
+
+    if args.verbose {
+        eprintln!(
+            "Solved {} letter sets ({} distinct) in {} seconds",
+            lines.len().num_format(),
+            outcome.solved.num_format(),
+            start_time.elapsed().as_secs_f64().num_format_sigdig(2)
+        );
+    }
+
+    // Write results
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for (line, result) in outcome.results.into_iter().enumerate() {
+        match args.format {
+            BatchFormat::Text => write_text(&mut out, line + 1, result)?,
+            BatchFormat::Json => write_json(&mut out, line + 1, result)?,
+        }
+    }
+
+    out.flush()
+}
+
+fn write_text(out: &mut impl Write, line: usize, result: BatchResult) -> io::Result<()> {
+    match result.words {
+        Ok(mut words) => {
+            // Longest words first
+            words.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+            write!(
+                out,
+                "{}: {} {}",
+                result.input,
+                words.len(),
+                if words.len() == 1 { "word" } else { "words" },
+            )?;
+
+            if !words.is_empty() {
+                write!(out, ": {}", words.join(" "))?;
+            }
+
+            writeln!(out)
+        }
+        Err(e) => writeln!(out, "{}: error: {} (line {})", result.input, e, line),
+    }
+}
+
+fn write_json(out: &mut impl Write, line: usize, result: BatchResult) -> io::Result<()> {
+    let record = match result.words {
+        Ok(words) => json!({
+            "line": line,
+            "input": result.input,
+            "words": words,
+        }),
+        Err(e) => json!({
+            "line": line,
+            "input": result.input,
+            "error": e.to_string(),
+        }),
+    };
+
+    writeln!(out, "{record}")
+}
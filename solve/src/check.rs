@@ -0,0 +1,89 @@
+//! Word check command
+
+use std::io;
+
+use clap::Args as ClapArgs;
+use dictionary::{Dictionary, WordSizeConstraint};
+use solver::{find_word_errors, find_words, SolverArgs, WordError};
+
+use crate::results::print_results;
+use crate::{validate_letters, Args};
+
+/// Check command arguments
+#[derive(ClapArgs)]
+pub struct CheckArgs {
+    /// Letters to use
+    #[clap(value_parser = validate_letters)]
+    letters: String,
+
+    /// Word to check
+    word: String,
+}
+
+/// Checks whether a word is allowed for a set of letters and explains why not
+pub fn check(args: &Args, check_args: &CheckArgs) -> io::Result<()> {
+    let letters = check_args.letters.as_str();
+    let word = check_args.word.to_ascii_uppercase();
+
+    // Load words of any length up to the longer of the letters and the word so the dictionary
+    // can be checked separately from the length and the letters
+    let mut size = WordSizeConstraint::default();
+
+    size.set_max(letters.len().max(word.len()));
+
+    let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
+
+    let errors = find_word_errors(&word, letters, &dictionary, args.min_len as usize);
+
+    if let Some(WordError::InvalidCharacter(c)) = errors.first() {
+        println!("{word} is not allowed: '{c}' is not a letter");
+        return Ok(());
+    }
+
+    // Dictionary
+    if errors.contains(&WordError::NotInDictionary) {
+        println!("{word} is not in the dictionary");
+    } else {
+        println!("{word} is in the dictionary");
+    }
+
+    // Letters
+    match errors
+        .iter()
+        .find(|e| matches!(e, WordError::UnavailableLetters { .. }))
+    {
+        Some(e) => println!("{word} can't be made from the letters: {e}"),
+        None => println!("{word} can be made from the letters"),
+    }
+
+    // Length
+    if let Some(WordError::TooShort { min, .. }) = errors
+        .iter()
+        .find(|e| matches!(e, WordError::TooShort { .. }))
+    {
+        println!("{word} is too short, words must have at least {min} letters");
+    }
+
+    if errors.is_empty() {
+        println!("{word} is allowed");
+    } else {
+        println!("{word} is not allowed");
+    }
+
+    // Longer words
+    let min_longer = (args.min_len as usize).max(word.len() + 1);
+
+    let longer = find_words(SolverArgs::new(letters, &dictionary))
+        .into_iter()
+        .filter(|w| w.len() >= min_longer)
+        .collect::<Vec<_>>();
+
+    if longer.is_empty() {
+        println!("No longer words are available");
+    } else {
+        println!("Longer words available:");
+        print_results(longer);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! Conundrum command
+
+use std::io;
+
+use clap::Args as ClapArgs;
+use dictionary::{Dictionary, WordSizeConstraint};
+use solver::{ConundrumGenerator, Rng, CONUNDRUM_LEN};
+
+use crate::Args;
+
+/// Conundrum command arguments
+#[derive(ClapArgs)]
+pub struct ConundrumArgs {
+    /// Random number seed (the same seed gives the same conundrum)
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+
+    /// Show the answer
+    #[clap(short = 'a', long = "answer")]
+    answer: bool,
+}
+
+/// Generates and prints a conundrum
+pub fn conundrum(args: &Args, conundrum_args: &ConundrumArgs) -> io::Result<()> {
+    // Load words up to the conundrum length
+    let mut size = WordSizeConstraint::default();
+
+    size.set_max(CONUNDRUM_LEN);
+
+    let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
+
+    let generator = ConundrumGenerator::new(&dictionary);
+
+    if args.verbose {
+        println!("{} possible answers", generator.answers().len());
+    }
+
+    let mut rng = match conundrum_args.seed {
+        Some(seed) => Rng::new(seed),
+        None => Rng::from_time(),
+    };
+
+    let Some(conundrum) = generator.generate(&mut rng) else {
+        eprintln!("No conundrums can be made from the dictionary");
+        std::process::exit(1);
+    };
+
+    println!(
+        "Conundrum: {} (difficulty {}/5)",
+        conundrum.scramble.join(" "),
+        conundrum.difficulty
+    );
+
+    if conundrum_args.answer {
+        println!("Answer: {}", conundrum.answer);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Daily challenge command
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use clap::Args as ClapArgs;
+use dictionary::{Dictionary, WordSizeConstraint};
+use game::{Daily, DailyRules, Date, HistoryEntry, Round, ROUND_LETTERS, ROUND_TIME};
+use solver::PileFrequencies;
+
+use crate::input::Input;
+use crate::play::{spaced, timed_word};
+use crate::results::print_results;
+use crate::{history, Args};
+
+/// Minimum word length for the daily challenge
+const DAILY_MIN_LEN: usize = 3;
+
+/// Daily command arguments
+#[derive(ClapArgs)]
+pub struct DailyArgs {
+    /// Date of the challenge (YYYY-MM-DD, defaults to today)
+    #[clap(long = "date")]
+    date: Option<Date>,
+
+    /// Seconds allowed to find a word
+    #[clap(long = "time", default_value_t = ROUND_TIME.as_secs())]
+    time: u64,
+}
+
+/// Plays the daily challenge
+pub fn daily(args: &Args, daily_args: &DailyArgs) -> io::Result<()> {
+    // The word length and piles are fixed so everyone with the same word list gets the same
+    // letters
+    let mut size = WordSizeConstraint::default();
+
+    size.set_min(DAILY_MIN_LEN);
+    size.set_max(ROUND_LETTERS);
+
+    let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
+
+    let date = daily_args.date.unwrap_or_else(Date::today);
+
+    let Some(daily) = Daily::new(
+        date,
+        &dictionary,
+        &PileFrequencies::countdown(),
+        &DailyRules::default(),
+    ) else {
+        eprintln!("No suitable letters found for {date}");
+        std::process::exit(1);
+    };
+
+    let mut round = Round::new(&dictionary);
+    round.set_letters(&daily.letters);
+
+    println!("Daily challenge {date}");
+    println!("Letters: {}", spaced(&daily.letters));
+    println!("Type your word and press enter before the time runs out");
+
+    let input = Input::new();
+
+    let time = Duration::from_secs(daily_args.time);
+    let start = Instant::now();
+
+    let word = timed_word(input.clock(time));
+
+    let taken = start.elapsed().min(time);
+
+    // Check the word
+    let declaration = round.declare(&word);
+
+    if word.is_empty() {
+        println!("No word declared");
+    } else if let Err(e) = &declaration.result {
+        println!("{} is not allowed: {}", declaration.word, e);
+    } else {
+        println!("{} is valid", declaration.word);
+    }
+
+    // Record the round
+    if let Some(history) = history(args) {
+        history.append(&HistoryEntry::new("daily", &round, &declaration, taken))?;
+    }
+
+    let word_len = declaration.is_valid().then_some(declaration.word.len());
+
+    println!();
+    println!("{}", daily.summary(word_len, declaration.score, taken));
+    println!();
+
+    println!("Best words:");
+    print_results(daily.best_words);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//! Draw probability command
+
+use std::io;
+
+use clap::Args as ClapArgs;
+use dictionary::{Dictionary, WordSizeConstraint};
+use numformat::NumFormat;
+use solver::{
+    draw_probability, likely_draws, normalise_letters, rank_by_draw_probability, LetterRules,
+};
+
+use crate::results::print_columns;
+use crate::{pile_frequencies, Args};
+
+/// Number of letters in a Countdown draw
+const DRAW_LEN: usize = 9;
+
+/// Draws command arguments
+#[derive(ClapArgs)]
+pub struct DrawsArgs {
+    /// Word to find the draws for
+    #[clap(value_parser = validate_word, required_unless_present = "study")]
+    word: Option<String>,
+
+    /// Number of vowels chosen (all Countdown choices if not given)
+    #[clap(long = "vowels", value_parser = clap::value_parser!(u8).range(0..=9))]
+    vowels: Option<u8>,
+
+    /// Number of draws or words to show for each choice
+    #[clap(short = 'n', long = "count", default_value_t = 10)]
+    count: usize,
+
+    /// List the words of this length most likely to be drawn
+    #[clap(long = "study", conflicts_with = "word")]
+    study: Option<usize>,
+}
+
+/// Prints draw probabilities for a word, or the words most likely to be drawn
+pub fn draws(args: &Args, draws_args: &DrawsArgs) -> io::Result<()> {
+    let piles = pile_frequencies(args);
+
+    // Vowel choices to report
+    let splits = match draws_args.vowels {
+        Some(vowels) => vec![vowels as usize],
+        None => {
+            let rules = LetterRules::countdown();
+            (rules.min_vowels..=DRAW_LEN - rules.min_consonants).collect()
+        }
+    };
+
+    match (&draws_args.word, draws_args.study) {
+        (Some(word), _) => {
+            for vowels in splits {
+                let consonants = DRAW_LEN - vowels;
+
+                println!(
+                    "== {} vowels, {} consonants: {} ==",
+                    vowels,
+                    consonants,
+                    percent(draw_probability(word, vowels, consonants, &piles))
+                );
+
+                let draws = likely_draws(word, vowels, consonants, &piles, draws_args.count)
+                    .into_iter()
+                    .map(|d| format!("{} {}", d.letters, percent(d.probability)))
+                    .collect::<Vec<_>>();
+
+                print_columns(&draws);
+            }
+        }
+        (None, Some(len)) => {
+            // Load words of the study length
+            let mut size = WordSizeConstraint::default();
+
+            size.set_min(len);
+            size.set_max(len);
+
+            let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
+
+            let words = dictionary.words_with_len(len);
+
+            for vowels in splits {
+                let consonants = DRAW_LEN - vowels;
+
+                println!(
+                    "== Most likely {} letter words with {} vowels, {} consonants ==",
+                    len, vowels, consonants
+                );
+
+                let ranked = rank_by_draw_probability(words.clone(), vowels, consonants, &piles)
+                    .into_iter()
+                    .take(draws_args.count)
+                    .map(|w| format!("{} {}", w.word, percent(w.probability)))
+                    .collect::<Vec<_>>();
+
+                print_columns(&ranked);
+            }
+        }
+        (None, None) => unreachable!(),
+    }
+
+    Ok(())
+}
+
+fn percent(probability: f64) -> String {
+    format!("{}%", (probability * 100.0).num_format_sigdig(3))
+}
+
+fn validate_word(s: &str) -> Result<String, String> {
+    normalise_letters(
+        s,
+        &LetterRules {
+            max_letters: Some(DRAW_LEN),
+            ..Default::default()
+        },
+    )
+    .map_err(|e| e.to_string())
+}
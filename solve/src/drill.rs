@@ -0,0 +1,228 @@
+//! Spaced repetition drill command
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args as ClapArgs;
+use dictionary::{Dictionary, WordSizeConstraint};
+use game::{missed_words, scramble, Date, DrillSchedule, Grade, ROUND_TIME};
+use solver::{check_word, find_top_words, time_seed, Rng, SolverArgs};
+
+use crate::input::{Input, Timed};
+use crate::play::spaced;
+use crate::results::print_results;
+use crate::{history, home_file, Args};
+
+/// Default drill schedule file name in the home directory
+const SCHEDULE_FILE: &str = ".countdown_drill.json";
+
+/// Drill command arguments
+#[derive(ClapArgs)]
+pub struct DrillArgs {
+    /// Add the best words missed in the history file
+    #[clap(long = "from-history")]
+    from_history: bool,
+
+    /// Add the words in a file (one per line)
+    #[clap(long = "words")]
+    words: Option<PathBuf>,
+
+    /// Minimum length of missed words to add from the history file
+    #[clap(long = "word-len", default_value_t = 7)]
+    word_len: usize,
+
+    /// Maximum number of words to review
+    #[clap(short = 'n', long = "count", default_value_t = 10)]
+    count: usize,
+
+    /// Drill schedule file (defaults to .countdown_drill.json in the home directory)
+    #[clap(long = "schedule")]
+    schedule: Option<PathBuf>,
+
+    /// Seconds allowed to find each word
+    #[clap(long = "time", default_value_t = ROUND_TIME.as_secs())]
+    time: u64,
+
+    /// Random number seed for the scrambles
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+}
+
+/// Reviews the words due in the drill schedule
+pub fn drill(args: &Args, drill_args: &DrillArgs) -> io::Result<()> {
+    let path = drill_args
+        .schedule
+        .clone()
+        .unwrap_or_else(|| home_file(SCHEDULE_FILE));
+
+    let mut schedule = DrillSchedule::load(&path)?;
+
+    let today = Date::today();
+
+    // Add new words
+    let mut new_words = Vec::new();
+
+    if drill_args.from_history {
+        match history(args) {
+            Some(history) => new_words.extend(missed_words(&history.load()?, drill_args.word_len)),
+            None => eprintln!("History is turned off, no words added from it"),
+        }
+    }
+
+    if let Some(file) = &drill_args.words {
+        for line in fs::read_to_string(file)?.lines() {
+            let word = line.trim();
+
+            if word.is_empty() {
+                continue;
+            }
+
+            if word.chars().all(|c| c.is_ascii_alphabetic()) {
+                new_words.push(word.to_ascii_uppercase());
+            } else {
+                eprintln!("Skipping '{word}', words must only contain letters");
+            }
+        }
+    }
+
+    if new_words.is_empty() && schedule.due(today).is_empty() {
+        print_nothing_due(&schedule);
+        return Ok(());
+    }
+
+    // Load words up to the length of the longest word
+    let mut size = WordSizeConstraint::default();
+
+    size.set_min(args.min_len as usize);
+    size.set_max(
+        schedule
+            .cards()
+            .iter()
+            .map(|c| c.word.as_str())
+            .chain(new_words.iter().map(|w| w.as_str()))
+            .map(|w| w.len())
+            .max()
+            .unwrap_or(0),
+    );
+
+    let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
+
+    // Words which aren't in the dictionary could never be answered
+    new_words.retain(|word| {
+        let found = dictionary.contains(word);
+
+        if !found {
+            eprintln!("Skipping {word}, it is not in the dictionary");
+        }
+
+        found
+    });
+
+    let added = new_words
+        .iter()
+        .filter(|word| schedule.add(word, today))
+        .count();
+
+    if added > 0 {
+        println!(
+            "Added {added} {} to {}",
+            if added == 1 { "word" } else { "words" },
+            path.display()
+        );
+        schedule.save(&path)?;
+    }
+
+    let due = schedule.due(today);
+
+    if due.is_empty() {
+        print_nothing_due(&schedule);
+        return Ok(());
+    }
+
+    let mut rng = Rng::new(drill_args.seed.unwrap_or_else(time_seed));
+    let input = Input::new();
+
+    let time = Duration::from_secs(drill_args.time);
+    let reviews = due.len().min(drill_args.count);
+    let mut reviewed = 0;
+    let mut correct = 0;
+
+    for (n, index) in due.into_iter().take(reviews).enumerate() {
+        let word = schedule.cards()[index].word.clone();
+        let letters = scramble(&word, &mut rng);
+
+        println!("== Word {} of {} ==", n + 1, reviews);
+        println!("Letters: {}", spaced(&letters));
+
+        let start = Instant::now();
+
+        // Late answers are still checked but graded hard
+        let answer = match input.clock(time) {
+            Timed::InTime(answer) | Timed::Late(answer) => answer,
+            Timed::Missed => String::new(),
+            Timed::Closed => break,
+        };
+
+        let taken = start.elapsed();
+
+        // Any word using all of the letters is correct
+        let result = check_word(&answer, &letters, &dictionary, word.len());
+
+        let grade = match &result {
+            Ok(_) if taken <= time / 3 => Grade::Easy,
+            Ok(_) if taken <= time => Grade::Good,
+            Ok(_) => Grade::Hard,
+            Err(_) => Grade::Again,
+        };
+
+        match &result {
+            Ok(found) if *found == word => println!("Correct"),
+            Ok(found) => println!("Correct, {found} also uses all of the letters"),
+            Err(_) if answer.is_empty() => println!("The word was {word}"),
+            Err(e) => println!(
+                "{} is not right ({}), the word was {word}",
+                answer.to_ascii_uppercase(),
+                e
+            ),
+        }
+
+        reviewed += 1;
+
+        if result.is_ok() {
+            correct += 1;
+        }
+
+        // Show the other words which can be made
+        let others = find_top_words(SolverArgs::new(&letters, &dictionary), 11)
+            .into_iter()
+            .filter(|w| *w != word)
+            .take(10)
+            .collect::<Vec<_>>();
+
+        if !others.is_empty() {
+            println!("Other words:");
+            print_results(others);
+        }
+
+        let card = schedule.card_mut(index);
+
+        card.review(grade, today);
+
+        println!("Next review on {}", card.due_date());
+
+        schedule.save(&path)?;
+    }
+
+    println!("{correct} of {reviewed} correct");
+
+    Ok(())
+}
+
+fn print_nothing_due(schedule: &DrillSchedule) {
+    match schedule.next_due() {
+        Some(date) => println!("No words are due, the next review is on {date}"),
+        None => println!("No words to drill, add some with --from-history or --words"),
+    }
+}
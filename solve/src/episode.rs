@@ -0,0 +1,204 @@
+//! Episode command
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use clap::Args as ClapArgs;
+use game::{Episode, HistoryEntry, Stage, EPISODE_ROUNDS, ROUND_TIME};
+use solver::{time_seed, ConundrumGenerator};
+
+use crate::input::{Input, Timed};
+use crate::play::{choose_letters, load_round_dictionary, spaced, timed_word};
+use crate::{history, pile_frequencies, Args};
+
+/// Time allowed to answer the conundrum after buzzing
+const ANSWER_TIME: Duration = Duration::from_secs(5);
+
+/// Episode command arguments
+#[derive(ClapArgs)]
+pub struct EpisodeArgs {
+    /// Number of letters rounds before the conundrum
+    #[clap(short = 'r', long = "rounds", default_value_t = EPISODE_ROUNDS)]
+    rounds: usize,
+
+    /// Random number seed (the same seed and choices give the same letters and conundrum)
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+
+    /// Seconds allowed for each round and the conundrum
+    #[clap(long = "time", default_value_t = ROUND_TIME.as_secs())]
+    time: u64,
+}
+
+/// Plays an interactive episode of letters rounds finishing with a conundrum
+pub fn episode(args: &Args, episode_args: &EpisodeArgs) -> io::Result<()> {
+    let dictionary = load_round_dictionary(args)?;
+
+    let generator = ConundrumGenerator::new(&dictionary);
+
+    let mut episode = Episode::new(
+        &dictionary,
+        pile_frequencies(args),
+        episode_args.rounds,
+        episode_args.seed.unwrap_or_else(time_seed),
+    );
+
+    let input = Input::new();
+    let time = Duration::from_secs(episode_args.time);
+
+    // Set if the input ends before the episode is over
+    let mut stopped = false;
+
+    while let Stage::Letters(number) = episode.stage() {
+        println!("== Round {} of {} ==", number, episode_args.rounds);
+
+        let mut round = episode.new_round();
+
+        // Choose the letters
+        if !choose_letters(&mut round, &input, |round, kind| {
+            episode.choose(round, kind)
+        }) {
+            stopped = true;
+            break;
+        }
+
+        // Start the clock
+        println!("Letters: {}", spaced(round.letters()));
+        println!("Type your word and press enter before the time runs out");
+
+        let start = Instant::now();
+
+        let word = timed_word(input.clock(time));
+
+        let taken = start.elapsed().min(time);
+
+        let result = episode.declare(&round, &word, taken);
+
+        let declaration = &result.declaration;
+
+        match &declaration.result {
+            _ if word.is_empty() => println!("No word declared"),
+            Ok(()) => println!(
+                "{} is valid, {}",
+                declaration.word,
+                points(declaration.score)
+            ),
+            Err(e) => println!("{} is not allowed: {}", declaration.word, e),
+        }
+
+        if !result.best.is_empty() {
+            println!(
+                "Best: {} ({})",
+                result.best.join(" "),
+                points(result.best_score)
+            );
+        }
+
+        // Record the round
+        if let Some(history) = history(args) {
+            history.append(&HistoryEntry::new("episode", &round, declaration, taken))?;
+        }
+
+        println!("Score: {}", points(episode.score()));
+        println!();
+    }
+
+    if stopped {
+        println!();
+        println!(
+            "Episode stopped after {} of {} rounds",
+            episode.letters_results().len(),
+            episode_args.rounds
+        );
+        print_summary(&episode);
+
+        return Ok(());
+    }
+
+    // Finish with the conundrum
+    println!("== Conundrum ==");
+
+    match episode.new_conundrum(&generator) {
+        Some(conundrum) => {
+            println!("Conundrum: {}", spaced(&conundrum.letters()));
+            println!("Press enter to buzz, or type your answer and press enter");
+
+            let start = Instant::now();
+
+            let (guess, taken) = match input.clock(time) {
+                Timed::InTime(line) if line.is_empty() => {
+                    // Buzzed, the answer must follow straight away
+                    let taken = start.elapsed().min(time);
+
+                    println!("Your answer:");
+
+                    (Some(timed_word(input.clock(ANSWER_TIME))), taken)
+                }
+                Timed::InTime(line) => (Some(line), start.elapsed().min(time)),
+                Timed::Late(_) | Timed::Missed | Timed::Closed => (None, time),
+            };
+
+            if let Some(result) = episode.answer_conundrum(guess.as_deref(), taken) {
+                match &result.guess {
+                    None => println!("No answer in time"),
+                    Some(_) if result.is_solved() => {
+                        println!("Correct, {}", points(result.score))
+                    }
+                    Some(guess) if guess.is_empty() => println!("No answer given"),
+                    Some(guess) => println!("{} is wrong", guess),
+                }
+
+                println!("The answer was {}", result.conundrum.answer);
+                println!();
+            }
+        }
+        None => println!("No conundrums can be made from the dictionary"),
+    }
+
+    print_summary(&episode);
+
+    Ok(())
+}
+
+/// Prints the results of each round and the total score
+fn print_summary(episode: &Episode) {
+    println!("== Summary ==");
+
+    for (i, result) in episode.letters_results().iter().enumerate() {
+        let word = if result.declaration.word.is_empty() {
+            "-"
+        } else {
+            &result.declaration.word
+        };
+
+        println!(
+            "Round {}: {}  {:9} {:2}  (best {} {})",
+            i + 1,
+            result.letters,
+            word,
+            result.declaration.score,
+            result.best.first().map_or("-", |w| w.as_str()),
+            result.best_score
+        );
+    }
+
+    if let Some(result) = episode.conundrum_result() {
+        println!(
+            "Conundrum: {}  {:9} {:2}  ({:.1}s)",
+            result.conundrum.letters(),
+            result
+                .guess
+                .as_deref()
+                .filter(|g| !g.is_empty())
+                .unwrap_or("-"),
+            result.score,
+            result.taken.as_secs_f64()
+        );
+    }
+
+    println!("Total: {} of {}", episode.score(), episode.max_score());
+}
+
+fn points(score: u32) -> String {
+    format!("{} {}", score, if score == 1 { "point" } else { "points" })
+}
@@ -0,0 +1,98 @@
+//! Interactive terminal input
+
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Reads lines from stdin on a background thread so input can be waited for with a timeout
+pub struct Input {
+    lines: Receiver<String>,
+}
+
+impl Input {
+    /// Starts reading lines from stdin
+    pub fn new() -> Self {
+        let (tx, lines) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self { lines }
+    }
+
+    /// Prints a prompt and waits for a line. Returns None at the end of the input
+    pub fn prompt(&self, prompt: &str) -> Option<String> {
+        print!("{prompt}");
+        let _ = io::stdout().flush();
+
+        self.lines.recv().ok().map(|line| line.trim().to_string())
+    }
+
+    /// Runs a clock until the time runs out or a line is entered, showing the time left at
+    /// intervals on separate lines so typing isn't overwritten. When the time runs out a line
+    /// entered within `LATE_TIME` is returned as late
+    pub fn clock(&self, time: Duration) -> Timed {
+        let start = Instant::now();
+        let mut shown = None;
+
+        loop {
+            let elapsed = start.elapsed();
+
+            if elapsed >= time {
+                break;
+            }
+
+            // Show the time left at the start, every ten seconds and for the last five seconds
+            let left = (time - elapsed).as_secs_f64().ceil() as u64;
+
+            if shown.is_none() || (shown != Some(left) && (left.is_multiple_of(10) || left <= 5)) {
+                println!(
+                    "[{left} {} left]",
+                    if left == 1 { "second" } else { "seconds" }
+                );
+                shown = Some(left);
+            }
+
+            match self.lines.recv_timeout(Duration::from_millis(100)) {
+                Ok(line) => return Timed::InTime(line.trim().to_string()),
+                Err(RecvTimeoutError::Timeout) => (),
+                Err(RecvTimeoutError::Disconnected) => return Timed::Closed,
+            }
+        }
+
+        println!("[Time's up!]");
+
+        // Catch a line which was being typed when the time ran out
+        match self.lines.recv_timeout(LATE_TIME) {
+            Ok(line) => Timed::Late(line.trim().to_string()),
+            Err(RecvTimeoutError::Timeout) => Timed::Missed,
+            Err(RecvTimeoutError::Disconnected) => Timed::Closed,
+        }
+    }
+}
+
+/// Time after the clock runs out in which a line is caught as late
+pub const LATE_TIME: Duration = Duration::from_secs(3);
+
+/// Result of running the clock
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Timed {
+    /// A line entered before the time ran out
+    InTime(String),
+    /// A line entered just after the time ran out
+    Late(String),
+    /// Nothing entered
+    Missed,
+    /// The input ended
+    Closed,
+}
@@ -2,52 +2,250 @@
 
 //! Countdown letters game solver
 
+mod batch;
+mod check;
+mod conundrum;
+mod daily;
+mod draws;
+mod drill;
+mod episode;
+mod input;
+mod numbers;
+mod play;
 mod results;
+mod simulate;
+mod stats;
+mod two_player;
 
+use std::env;
+use std::fs;
 use std::io;
-use std::path::Path;
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use dictionary::{Dictionary, WordSizeConstraint};
+use game::History;
 use numformat::NumFormat;
-use solver::{find_words, SolverArgs};
-
-use crate::results::print_results;
+use solver::{
+    find_near_misses, find_phrases, find_top_words, is_vowel, normalise_letters, rate_letters,
+    score_words, search_top_words, search_words, time_seed, CountdownScorer, LengthScorer,
+    LengthSquaredScorer, LetterFrequencies, LetterPiles, LetterRules, PhraseArgs, PileFrequencies,
+    PileKind, Rng, Scorer, ScrabbleScorer, SearchLimits, SearchStats, SignatureIndex, SolverArgs,
+    SolverBackend, TextTraceSink, TraceSink, TrieBackend, WordFrequencies, WordFrequencyError,
+};
+
+use crate::batch::{batch, BatchFormat};
+use crate::check::{check, CheckArgs};
+use crate::conundrum::{conundrum, ConundrumArgs};
+use crate::daily::{daily, DailyArgs};
+use crate::draws::{draws, DrawsArgs};
+use crate::drill::{drill, DrillArgs};
+use crate::episode::{episode, EpisodeArgs};
+use crate::numbers::{numbers, NumbersArgs};
+use crate::play::{play, PlayArgs};
+use crate::results::{
+    print_difficulty, print_near_misses, print_phrases, print_results, print_scored_results,
+};
+use crate::simulate::{simulate, SimulateArgs};
+use crate::stats::{stats, StatsArgs};
+use crate::two_player::{two_player, TwoPlayerArgs};
 
 /// Countdown letters game solver
 #[derive(Parser, Default)]
-#[clap(author, version, about)]
+#[clap(
+    author,
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Args {
+    /// Command to run instead of solving
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Letters to use
-    #[clap(value_parser = validate_letters)]
-    letters: String,
+    #[clap(value_parser = validate_letters, required_unless_present_any = ["batch", "draw"])]
+    letters: Option<String>,
+
+    /// Draw the letters from the piles with a sequence of vowel and consonant choices, eg. VCCVCCVCC
+    #[clap(long = "draw", conflicts_with_all = ["letters", "batch"])]
+    draw: Option<String>,
+
+    /// Random number seed for --draw (the same seed gives the same letters)
+    #[clap(long = "seed", requires = "draw")]
+    seed: Option<u64>,
+
+    /// Vowel pile tile counts, eg. "A15 E21 I13 O13 U5"
+    #[clap(long = "vowel-pile", global = true, value_parser = validate_vowel_pile)]
+    vowel_pile: Option<LetterFrequencies>,
+
+    /// Consonant pile tile counts, eg. "B2 C3 D6 ..."
+    #[clap(long = "consonant-pile", global = true, value_parser = validate_consonant_pile)]
+    consonant_pile: Option<LetterFrequencies>,
+
+    /// Solve each line of a file as a letter set ('-' for stdin)
+    #[clap(long = "batch", conflicts_with_all = ["letters", "best", "top", "score", "phrases", "enumeration", "near_miss", "rate", "timeout", "max_nodes", "debug"])]
+    batch: Option<String>,
+
+    /// Output format for --batch
+    #[clap(long = "format", value_enum, default_value_t = BatchFormat::Text)]
+    format: BatchFormat,
 
     /// Word list file
     #[clap(
         short = 'd',
         long = "dictionary",
         default_value_t = default_dict().into(),
+        global = true
     )]
     dictionary_file: String,
 
     /// Minimum word length to find
-    #[clap(short = 'm', long = "min-len", default_value_t = 3)]
+    #[clap(short = 'm', long = "min-len", default_value_t = 3, global = true)]
     min_len: u8,
 
+    /// Check the letters follow the Countdown rules (9 letters, at least 3 vowels and 4 consonants)
+    #[clap(short = 'c', long = "countdown")]
+    countdown: bool,
+
     /// Allow letters to be used more than once
     #[clap(short = 'r', long = "reuse")]
     reuse_letters: bool,
 
+    /// Only find the best word (single threaded, trie backend only)
+    #[clap(short = 'b', long = "best", conflicts_with_all = ["top", "threads"])]
+    best: bool,
+
+    /// Only find the best N words (single threaded, trie backend only)
+    #[clap(short = 't', long = "top", conflicts_with = "threads")]
+    top: Option<usize>,
+
+    /// Find phrases using all of the letters (trie backend only)
+    #[clap(short = 'p', long = "phrases", conflicts_with_all = ["best", "top", "score", "reuse_letters"])]
+    phrases: bool,
+
+    /// Word lengths for phrases, eg. 5,4 (implies --phrases)
+    #[clap(short = 'e', long = "enumeration", value_delimiter = ',', conflicts_with_all = ["best", "top", "score", "reuse_letters"])]
+    enumeration: Vec<usize>,
+
+    /// Find words which could be made with one more letter or one letter changed (trie backend
+    /// only)
+    #[clap(long = "near-miss", conflicts_with_all = ["best", "top", "score", "reuse_letters", "phrases", "enumeration"])]
+    near_miss: bool,
+
+    /// Rate the difficulty of the letters
+    #[clap(long = "rate", conflicts_with_all = ["phrases", "enumeration", "near_miss", "reuse_letters"])]
+    rate: bool,
+
+    /// Word usage frequency file (lines of a word and a count) used to judge how obscure words are
+    #[clap(long = "frequencies", global = true)]
+    frequencies: Option<String>,
+
+    /// Rank words using a scoring model and show the scores
+    #[clap(short = 's', long = "score", value_enum)]
+    score: Option<ScoreModel>,
+
+    /// Search implementation (the index is quicker for many short searches but takes time to build)
+    #[clap(long = "backend", value_enum, default_value_t = Backend::Trie, global = true)]
+    backend: Backend,
+
+    /// Number of threads to search with (0 to use all available cores). Defaults to 1, or all
+    /// available cores for --batch and simulations
+    #[clap(short = 'j', long = "threads", global = true)]
+    threads: Option<usize>,
+
+    /// Stop searching after this many seconds
+    #[clap(long = "timeout", value_parser = validate_timeout)]
+    timeout: Option<Duration>,
+
+    /// Stop searching after visiting this many dictionary tree nodes
+    #[clap(long = "max-nodes")]
+    max_nodes: Option<u64>,
+
+    /// History file recording played rounds (defaults to .countdown_history.jsonl in the home
+    /// directory)
+    #[clap(long = "history-file", global = true)]
+    history_file: Option<PathBuf>,
+
+    /// Don't record played rounds in the history file
+    #[clap(long = "no-history", global = true)]
+    no_history: bool,
+
     /// Verbose output
-    #[clap(short = 'v', long = "verbose")]
+    #[clap(short = 'v', long = "verbose", global = true)]
     verbose: bool,
 
-    /// Debug output
-    #[clap(long = "debug")]
+    /// Debug output (trie backend only)
+    #[clap(long = "debug", conflicts_with_all = ["phrases", "enumeration", "near_miss"])]
     debug: bool,
 }
 
+/// Commands
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a conundrum (a scrambled nine letter word)
+    Conundrum(ConundrumArgs),
+    /// Show the chance of drawing the letters for a word and the draws it is most likely in
+    Draws(DrawsArgs),
+    /// Simulate draws to compare vowel and consonant choices
+    Simulate(SimulateArgs),
+    /// Play a timed letters round
+    Play(PlayArgs),
+    /// Play a full episode of letters rounds finishing with a conundrum
+    Episode(EpisodeArgs),
+    /// Play a two player game on one terminal
+    TwoPlayer(TwoPlayerArgs),
+    /// Play the daily challenge
+    ///
+    /// The letters for a date are drawn from the Countdown piles with a minimum word length of 3,
+    /// whatever --vowel-pile, --consonant-pile and --min-len are set to. They also depend on the
+    /// word list, so the result summary includes a code for the letters which players can compare
+    /// to check they had the same challenge
+    Daily(DailyArgs),
+    /// Check whether a word can be made from the letters and is in the dictionary
+    Check(CheckArgs),
+    /// Report statistics for played rounds
+    Stats(StatsArgs),
+    /// Drill missed words with spaced repetition
+    Drill(DrillArgs),
+    /// Solve a numbers round
+    Numbers(NumbersArgs),
+}
+
+/// Solver backends
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum Backend {
+    /// Walk the dictionary tree
+    #[default]
+    Trie,
+    /// Look up letter combinations in an anagram signature index
+    Index,
+}
+
+/// Word scoring models
+#[derive(Clone, Copy, ValueEnum)]
+enum ScoreModel {
+    /// Countdown rules (one point per letter, 18 for a nine letter word)
+    Countdown,
+    /// Sum of Scrabble tile values
+    Scrabble,
+    /// Square of the word length
+    LengthSquared,
+}
+
+impl ScoreModel {
+    fn scorer(&self) -> &'static dyn Scorer {
+        match self {
+            ScoreModel::Countdown => &CountdownScorer,
+            ScoreModel::Scrabble => &ScrabbleScorer,
+            ScoreModel::LengthSquared => &LengthSquaredScorer,
+        }
+    }
+}
+
 fn main() -> io::Result<()> {
     // Parse command line arguments
     let args = Args::parse();
@@ -64,12 +262,70 @@ fn main() -> io::Result<()> {
         std::process::exit(1);
     }
 
+    // Run command
+    if let Some(command) = &args.command {
+        return match command {
+            Command::Conundrum(conundrum_args) => conundrum(&args, conundrum_args),
+            Command::Draws(draws_args) => draws(&args, draws_args),
+            Command::Simulate(simulate_args) => simulate(&args, simulate_args),
+            Command::Play(play_args) => play(&args, play_args),
+            Command::Episode(episode_args) => episode(&args, episode_args),
+            Command::TwoPlayer(two_player_args) => two_player(&args, two_player_args),
+            Command::Daily(daily_args) => daily(&args, daily_args),
+            Command::Check(check_args) => check(&args, check_args),
+            Command::Stats(stats_args) => stats(&args, stats_args),
+            Command::Drill(drill_args) => drill(&args, drill_args),
+            Command::Numbers(numbers_args) => numbers(&args, numbers_args),
+        };
+    }
+
+    // Batch solve
+    if let Some(file) = &args.batch {
+        return batch(&args, file);
+    }
+
+    let letters = match &args.draw {
+        Some(choices) => draw_letters(&args, choices),
+        None => args.letters.clone().expect("letters are required"),
+    };
+
+    let letters = letters.as_str();
+
+    // Searches which walk the dictionary tree directly can't use the index
+    if matches!(args.backend, Backend::Index) {
+        let tree_only = [
+            (args.best, "--best"),
+            (args.top.is_some(), "--top"),
+            (args.phrases || !args.enumeration.is_empty(), "--phrases"),
+            (args.near_miss, "--near-miss"),
+            (args.debug, "--debug"),
+        ];
+
+        if let Some((_, name)) = tree_only.iter().find(|(given, _)| *given) {
+            Args::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    format!("{name} can't be used with --backend index"),
+                )
+                .exit();
+        }
+    }
+
+    // Check Countdown rules
+    if args.countdown {
+        if let Err(e) = normalise_letters(letters, &LetterRules::countdown()) {
+            Args::command()
+                .error(ErrorKind::ValueValidation, e.to_string())
+                .exit();
+        }
+    }
+
     // Print details
     if args.verbose {
         println!(
             "{} letters: {}",
-            args.letters.len(),
-            args.letters
+            letters.len(),
+            letters
                 .chars()
                 .map(|c| c.to_string())
                 .collect::<Vec<String>>()
@@ -83,7 +339,8 @@ fn main() -> io::Result<()> {
     size.set_min(args.min_len as usize);
 
     if !args.reuse_letters {
-        size.set_max(args.letters.len());
+        // Near misses can be one letter longer
+        size.set_max(letters.len() + usize::from(args.near_miss));
     }
 
     let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
@@ -91,44 +348,280 @@ fn main() -> io::Result<()> {
     // Find words
     let start_time = Instant::now();
 
-    let words = find_words(SolverArgs {
-        letters: &args.letters,
-        dictionary: &dictionary,
+    if args.phrases || !args.enumeration.is_empty() {
+        let phrases = find_phrases(PhraseArgs {
+            letters,
+            dictionary: &dictionary,
+            enumeration: &args.enumeration,
+            min_len: args.min_len as usize,
+        });
+
+        print_search_time(&args, start_time, None);
+
+        // Print results
+        print_phrases(phrases);
+
+        return Ok(());
+    }
+
+    if args.near_miss {
+        // Only show added letter words at least as long as the best word
+        let best_len = find_top_words(SolverArgs::new(letters, &dictionary), 1)
+            .first()
+            .map_or(0, |w| w.len());
+
+        let mut near_misses = find_near_misses(letters, &dictionary);
+
+        near_misses.added.retain(|n| n.word.len() >= best_len);
+
+        print_search_time(&args, start_time, None);
+
+        // Print results
+        print_near_misses(near_misses);
+
+        return Ok(());
+    }
+
+    // Build the signature index if needed
+    let index = signature_index(&args, &dictionary, args.verbose);
+
+    let start_time = Instant::now();
+
+    // Debug output of every dictionary lookup
+    let trace = args.debug.then(TextTraceSink::stdout);
+
+    let solver_args = SolverArgs {
         reuse_letters: args.reuse_letters,
-        debug: args.debug,
+        trace: trace.as_ref().map(|t| t as &dyn TraceSink),
+        threads: args.threads.unwrap_or(1),
+        limits: SearchLimits {
+            deadline: args
+                .timeout
+                .and_then(|timeout| start_time.checked_add(timeout)),
+            max_nodes: args.max_nodes,
+            ..Default::default()
+        },
+        backend: solver_backend(&index),
+        ..SolverArgs::new(letters, &dictionary)
+    };
+
+    // Rate the letters before the search arguments are consumed
+    let difficulty = args.rate.then(|| {
+        rate_letters(
+            SolverArgs {
+                backend: solver_backend(&index),
+                ..SolverArgs::new(letters, &dictionary)
+            },
+            word_frequencies(&args).as_ref(),
+        )
     });
 
-    if args.verbose {
-        println!(
-            "Search took {} seconds",
-            start_time.elapsed().as_secs_f64().num_format_sigdig(2)
-        );
+    let top = if args.best { Some(1) } else { args.top };
+
+    match args.score {
+        Some(model) => {
+            let scorer = model.scorer();
+
+            let (words, stats) = match top {
+                Some(count) => {
+                    let outcome = search_top_words(solver_args, count, scorer);
+                    print_truncated(outcome.truncated);
+                    (outcome.words, None)
+                }
+                None => {
+                    let outcome = search_words(solver_args);
+                    print_truncated(outcome.truncated);
+                    (score_words(outcome.words, scorer), Some(outcome.stats))
+                }
+            };
+
+            print_search_time(&args, start_time, stats.as_ref());
+
+            // Print results
+            print_scored_results(words);
+        }
+        None => {
+            let (words, stats) = match top {
+                Some(count) => {
+                    let outcome = search_top_words(solver_args, count, &LengthScorer);
+                    print_truncated(outcome.truncated);
+                    (outcome.words.into_iter().map(|w| w.word).collect(), None)
+                }
+                None => {
+                    let outcome = search_words(solver_args);
+                    print_truncated(outcome.truncated);
+                    (outcome.words, Some(outcome.stats))
+                }
+            };
+
+            print_search_time(&args, start_time, stats.as_ref());
+
+            // Print results
+            print_results(words);
+        }
     }
 
-    // Print results
-    print_results(words);
+    if let Some(difficulty) = difficulty {
+        print_difficulty(&difficulty);
+    }
 
     Ok(())
 }
 
-fn validate_letters(s: &str) -> Result<String, String> {
-    // Check minimum length
-    if s.len() < 2 {
-        Err("At least 2 letters must be provided")?;
+fn draw_letters(args: &Args, choices: &str) -> String {
+    let seed = args.seed.unwrap_or_else(time_seed);
+
+    let letters = LetterPiles::new(&pile_frequencies(args))
+        .draw_sequence(choices, &mut Rng::new(seed))
+        .map_err(|e| e.to_string())
+        .and_then(|letters| {
+            normalise_letters(&letters, &letter_rules(false)).map_err(|e| e.to_string())
+        });
+
+    match letters {
+        Ok(letters) => {
+            println!("Letters drawn: {letters} (seed {seed})");
+            letters
+        }
+        Err(e) => Args::command().error(ErrorKind::ValueValidation, e).exit(),
+    }
+}
+
+fn pile_frequencies(args: &Args) -> PileFrequencies {
+    let countdown = PileFrequencies::countdown();
+
+    PileFrequencies {
+        vowels: args.vowel_pile.clone().unwrap_or(countdown.vowels),
+        consonants: args.consonant_pile.clone().unwrap_or(countdown.consonants),
+    }
+}
+
+fn word_frequencies(args: &Args) -> Option<WordFrequencies> {
+    let file = args.frequencies.as_ref()?;
+
+    let frequencies = fs::read_to_string(file)
+        .map_err(|e| format!("Unable to read {file}: {e}"))
+        .and_then(|s| s.parse().map_err(|e: WordFrequencyError| e.to_string()));
+
+    match frequencies {
+        Ok(frequencies) => Some(frequencies),
+        Err(e) => Args::command().error(ErrorKind::Io, e).exit(),
+    }
+}
+
+fn history(args: &Args) -> Option<History> {
+    if args.no_history {
+        return None;
     }
 
-    // Convert all letters to upper case
-    let ustring = s
-        .chars()
-        .map(|c| c.to_ascii_uppercase())
-        .collect::<String>();
+    let path = match &args.history_file {
+        Some(path) => path.clone(),
+        None => home_file(HISTORY_FILE),
+    };
+
+    Some(History::new(&path))
+}
+
+fn home_file(name: &str) -> PathBuf {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(name)
+}
+
+fn signature_index(args: &Args, dictionary: &Dictionary, verbose: bool) -> Option<SignatureIndex> {
+    match args.backend {
+        Backend::Trie => None,
+        Backend::Index => {
+            let start_time = Instant::now();
+
+            let index = SignatureIndex::new(dictionary);
+
+            if verbose {
+                println!(
+                    "Index built with {} signatures in {} seconds",
+                    index.signature_count().num_format(),
+                    start_time.elapsed().as_secs_f64().num_format_sigdig(2)
+                );
+            }
 
-    // Check we only have upper case ascii characters
-    if !ustring.chars().all(|c| c.is_ascii_uppercase()) {
-        Err("Letters must be A-Z only".to_string())?;
+            Some(index)
+        }
+    }
+}
+
+fn solver_backend(index: &Option<SignatureIndex>) -> &dyn SolverBackend {
+    match index {
+        Some(index) => index,
+        None => &TrieBackend,
     }
+}
 
-    Ok(ustring)
+fn print_search_time(args: &Args, start_time: Instant, stats: Option<&SearchStats>) {
+    if args.verbose {
+        let elapsed = start_time.elapsed().as_secs_f64().num_format_sigdig(2);
+
+        match stats {
+            Some(stats) if stats.threads > 1 => println!(
+                "Search took {} seconds ({}x speed-up on {} threads)",
+                elapsed,
+                stats.speed_up().num_format_sigdig(2),
+                stats.threads
+            ),
+            _ => println!("Search took {elapsed} seconds"),
+        }
+    }
+}
+
+fn print_truncated(truncated: bool) {
+    if truncated {
+        println!("Search limit reached, results are incomplete");
+    }
+}
+
+fn validate_letters(s: &str) -> Result<String, String> {
+    // Convert all letters to upper case, removing any spaces, and check at least 2 letters
+    normalise_letters(s, &letter_rules(false)).map_err(|e| e.to_string())
+}
+
+fn validate_vowel_pile(s: &str) -> Result<LetterFrequencies, String> {
+    validate_pile(s, PileKind::Vowel)
+}
+
+fn validate_consonant_pile(s: &str) -> Result<LetterFrequencies, String> {
+    validate_pile(s, PileKind::Consonant)
+}
+
+fn validate_pile(s: &str, kind: PileKind) -> Result<LetterFrequencies, String> {
+    let frequencies = s.parse::<LetterFrequencies>().map_err(|e| e.to_string())?;
+
+    // Check every letter belongs in the pile
+    if let Some((letter, _)) = frequencies
+        .iter()
+        .find(|(letter, _)| is_vowel(*letter) != (kind == PileKind::Vowel))
+    {
+        Err(format!("{letter} can't be in the {} pile", kind.name()))?;
+    }
+
+    Ok(frequencies)
+}
+
+fn validate_timeout(s: &str) -> Result<Duration, String> {
+    let secs = s.parse::<f64>().map_err(|e| e.to_string())?;
+
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
+fn letter_rules(countdown: bool) -> LetterRules {
+    if countdown {
+        LetterRules::countdown()
+    } else {
+        LetterRules {
+            min_letters: 2,
+            ..Default::default()
+        }
+    }
 }
 
 const DICTS: [&str; 3] = [
@@ -137,6 +630,9 @@ const DICTS: [&str; 3] = [
     "/etc/dictionaries-common/words",
 ];
 
+/// Default history file name in the home directory
+const HISTORY_FILE: &str = ".countdown_history.jsonl";
+
 fn default_dict() -> &'static str {
     DICTS
         .iter()
@@ -151,3 +647,18 @@ fn dict_valid(dict: &str) -> Option<String> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout() {
+        assert_eq!(validate_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(validate_timeout("0"), Ok(Duration::ZERO));
+
+        for invalid in ["-1", "NaN", "inf", "1e30", "soon"] {
+            assert!(validate_timeout(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Numbers game command
+
+use std::io;
+use std::time::Instant;
+
+use clap::error::ErrorKind;
+use clap::{Args as ClapArgs, CommandFactory};
+use numbers::{check_countdown, solve_numbers, MAX_NUMBERS};
+
+use crate::results::print_solutions;
+use crate::{print_search_time, Args};
+
+/// Numbers command arguments
+#[derive(ClapArgs)]
+pub struct NumbersArgs {
+    /// Numbers to use
+    #[clap(num_args = 1..=MAX_NUMBERS, required = true, value_parser = clap::value_parser!(u32).range(1..))]
+    numbers: Vec<u32>,
+
+    /// Target to reach
+    #[clap(short = 't', long = "target", value_parser = clap::value_parser!(u32).range(1..))]
+    target: u32,
+
+    /// Check the numbers and target follow the Countdown rules (six numbers from two each of 1
+    /// to 10 and one each of 25, 50, 75 and 100, and a target from 101 to 999)
+    #[clap(short = 'c', long = "countdown")]
+    countdown: bool,
+
+    /// Number of solutions to show (0 for all)
+    #[clap(short = 'n', long = "count", default_value_t = 10)]
+    count: usize,
+}
+
+/// Finds exact or closest solutions for a numbers round
+pub fn numbers(args: &Args, numbers_args: &NumbersArgs) -> io::Result<()> {
+    if numbers_args.countdown {
+        if let Err(e) = check_countdown(&numbers_args.numbers, numbers_args.target) {
+            Args::command()
+                .error(ErrorKind::ValueValidation, e.to_string())
+                .exit();
+        }
+    }
+
+    if args.verbose {
+        println!(
+            "Numbers: {}, target {}",
+            numbers_args
+                .numbers
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+            numbers_args.target
+        );
+    }
+
+    let start_time = Instant::now();
+
+    let solutions = solve_numbers(&numbers_args.numbers, numbers_args.target);
+
+    print_search_time(args, start_time, None);
+
+    print_solutions(&solutions, numbers_args.count);
+
+    Ok(())
+}
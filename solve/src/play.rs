@@ -0,0 +1,217 @@
+//! Interactive letters round
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use clap::{Args as ClapArgs, ValueEnum};
+use dictionary::{Dictionary, WordSizeConstraint};
+use game::{adjudicate, Entry, HistoryEntry, Round, RoundError, ROUND_LETTERS, ROUND_TIME};
+use solver::{time_seed, Bot, LetterPiles, PileKind, Rng, Skill};
+
+use crate::input::{Input, Timed};
+use crate::results::print_results;
+use crate::{history, pile_frequencies, word_frequencies, Args};
+
+/// Play command arguments
+#[derive(ClapArgs)]
+pub struct PlayArgs {
+    /// Random number seed (the same seed and choices give the same letters)
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+
+    /// Seconds allowed to find a word
+    #[clap(long = "time", default_value_t = ROUND_TIME.as_secs())]
+    time: u64,
+
+    /// Number of best words to show at the end of the round
+    #[clap(short = 'n', long = "show", default_value_t = 10)]
+    show: usize,
+
+    /// Play against a computer opponent of this skill level
+    #[clap(long = "bot", value_enum)]
+    bot: Option<BotSkill>,
+}
+
+/// Computer opponent skill levels
+#[derive(Clone, Copy, ValueEnum)]
+enum BotSkill {
+    /// Often misses long and obscure words
+    Beginner,
+    /// Usually finds a good word
+    Average,
+    /// Rarely misses a word
+    Expert,
+    /// Always finds the best word
+    Perfect,
+}
+
+impl BotSkill {
+    fn skill(&self) -> Skill {
+        match self {
+            BotSkill::Beginner => Skill::Beginner,
+            BotSkill::Average => Skill::Average,
+            BotSkill::Expert => Skill::Expert,
+            BotSkill::Perfect => Skill::Perfect,
+        }
+    }
+}
+
+/// Plays an interactive letters round
+pub fn play(args: &Args, play_args: &PlayArgs) -> io::Result<()> {
+    let dictionary = load_round_dictionary(args)?;
+
+    let mut piles = LetterPiles::new(&pile_frequencies(args));
+    let mut rng = Rng::new(play_args.seed.unwrap_or_else(time_seed));
+
+    let input = Input::new();
+
+    let mut round = Round::new(&dictionary);
+
+    // Choose the letters
+    if !choose_letters(&mut round, &input, |round, kind| {
+        round.choose(kind, &mut piles, &mut rng)
+    }) {
+        return Ok(());
+    }
+
+    // Start the clock
+    println!("Letters: {}", spaced(round.letters()));
+    println!("Type your word and press enter before the time runs out");
+
+    let time = Duration::from_secs(play_args.time);
+    let start = Instant::now();
+
+    let word = timed_word(input.clock(time));
+
+    let taken = start.elapsed().min(time);
+
+    // Check the word
+    let declaration = round.declare(&word);
+
+    if word.is_empty() {
+        println!("No word declared");
+    } else {
+        match &declaration.result {
+            Ok(()) => println!(
+                "{} is valid, {} {}",
+                declaration.word,
+                declaration.score,
+                if declaration.score == 1 {
+                    "point"
+                } else {
+                    "points"
+                }
+            ),
+            Err(e) => println!("{} is not allowed: {}", declaration.word, e),
+        }
+    }
+
+    // Computer opponent
+    if let Some(skill) = play_args.bot {
+        let frequencies = word_frequencies(args);
+
+        let bot = Bot {
+            frequencies: frequencies.as_ref(),
+            ..Bot::new(skill.skill())
+        };
+
+        let bot_word = bot.declare(&round.words(), &mut rng).unwrap_or_default();
+
+        if bot_word.is_empty() {
+            println!("The {} bot didn't find a word", bot.skill.name());
+        } else {
+            println!("The {} bot declares {}", bot.skill.name(), bot_word);
+        }
+
+        let [player, bot] = adjudicate(
+            &round,
+            &[
+                Entry::new(word.len(), &word),
+                Entry::new(bot_word.len(), &bot_word),
+            ],
+        );
+
+        println!("You score {}, the bot scores {}", player.score, bot.score);
+    }
+
+    // Record the round
+    if let Some(history) = history(args) {
+        history.append(&HistoryEntry::new("play", &round, &declaration, taken))?;
+    }
+
+    // Reveal the best words
+    println!("Best words:");
+    print_results(round.top_words(play_args.show));
+
+    Ok(())
+}
+
+/// Asks for vowel or consonant choices until all of the letters are chosen.
+/// Returns false if the input ends
+pub fn choose_letters(
+    round: &mut Round,
+    input: &Input,
+    mut choose: impl FnMut(&mut Round, PileKind) -> Result<char, RoundError>,
+) -> bool {
+    while !round.is_complete() {
+        let prompt = format!(
+            "Letters: {:width$}  Vowel or consonant? (v/c): ",
+            spaced(round.letters()),
+            width = ROUND_LETTERS * 2 - 1
+        );
+
+        let Some(choice) = input.prompt(&prompt) else {
+            return false;
+        };
+
+        let kind = match choice.to_ascii_lowercase().as_str() {
+            "v" | "vowel" => PileKind::Vowel,
+            "c" | "consonant" => PileKind::Consonant,
+            _ => {
+                println!("Please enter v or c");
+                continue;
+            }
+        };
+
+        if let Err(e) = choose(round, kind) {
+            println!("{e}");
+        }
+    }
+
+    true
+}
+
+/// Returns the word entered against the clock. Words entered after the time ran out are
+/// rejected
+pub fn timed_word(timed: Timed) -> String {
+    match timed {
+        Timed::InTime(word) => word,
+        Timed::Late(word) => {
+            if !word.is_empty() {
+                println!("{} was entered too late", word.to_ascii_uppercase());
+            }
+
+            String::new()
+        }
+        Timed::Missed | Timed::Closed => String::new(),
+    }
+}
+
+/// Loads the dictionary with words up to the round length
+pub fn load_round_dictionary(args: &Args) -> io::Result<Dictionary> {
+    let mut size = WordSizeConstraint::default();
+
+    size.set_min(args.min_len as usize);
+    size.set_max(ROUND_LETTERS);
+
+    Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)
+}
+
+/// Returns letters separated by spaces
+pub fn spaced(letters: &str) -> String {
+    letters
+        .chars()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
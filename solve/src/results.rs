@@ -1,6 +1,8 @@
 use std::cmp::{max, Ordering};
 
+use numbers::Solutions;
 use numformat::NumFormat;
+use solver::{Difficulty, NearMiss, NearMisses, ScoredWord};
 #[cfg(any(unix, windows))]
 use terminal_size::{terminal_size, Width};
 
@@ -16,11 +18,7 @@ pub fn print_results(mut words: Vec<String>) {
         result
     });
 
-    println!(
-        "{} {} found",
-        words.len().num_format(),
-        if words.len() == 1 { "word" } else { "words" }
-    );
+    print_word_count(words.len());
 
     // Group words by length
     let mut last_len = 0;
@@ -60,6 +58,192 @@ pub fn print_results(mut words: Vec<String>) {
     }
 }
 
+/// Prints words ranked by score, grouped by score
+pub fn print_scored_results(mut words: Vec<ScoredWord>) {
+    // Sort words by highest score, then longest, then alphabetical
+    words.sort_by(ScoredWord::rank_cmp);
+
+    print_word_count(words.len());
+
+    for group in words.chunk_by(|a, b| a.score == b.score) {
+        let score = group[0].score;
+
+        println!(
+            "== {} {} ({}) ==",
+            score.num_format(),
+            if score == 1 { "point" } else { "points" },
+            group.len()
+        );
+
+        let words = group.iter().map(|s| s.word.clone()).collect::<Vec<_>>();
+
+        print_columns(&words);
+    }
+}
+
+/// Prints phrases grouped by number of words
+pub fn print_phrases(phrases: Vec<Vec<String>>) {
+    println!(
+        "{} {} found",
+        phrases.len().num_format(),
+        if phrases.len() == 1 {
+            "phrase"
+        } else {
+            "phrases"
+        }
+    );
+
+    for group in phrases.chunk_by(|a, b| a.len() == b.len()) {
+        println!("== {} word phrases ({}) ==", group[0].len(), group.len());
+
+        let phrases = group.iter().map(|p| p.join(" ")).collect::<Vec<_>>();
+
+        print_columns(&phrases);
+    }
+}
+
+/// Prints near miss words grouped by length
+pub fn print_near_misses(near_misses: NearMisses) {
+    print_near_miss_group("with one more letter", &near_misses.added);
+    print_near_miss_group("with one letter changed", &near_misses.swapped);
+}
+
+fn print_near_miss_group(desc: &str, near_misses: &[NearMiss]) {
+    println!(
+        "{} {} {}",
+        near_misses.len().num_format(),
+        if near_misses.len() == 1 {
+            "word"
+        } else {
+            "words"
+        },
+        desc
+    );
+
+    for group in near_misses.chunk_by(|a, b| a.word.len() == b.word.len()) {
+        println!(
+            "== {} letter words ({}) ==",
+            group[0].word.len(),
+            group.len()
+        );
+
+        let words = group
+            .iter()
+            .map(|n| match n.removed {
+                Some(removed) => format!("{} (-{} +{})", n.word, removed, n.added),
+                None => format!("{} (+{})", n.word, n.added),
+            })
+            .collect::<Vec<_>>();
+
+        print_columns(&words);
+    }
+}
+
+/// Prints a difficulty rating
+pub fn print_difficulty(difficulty: &Difficulty) {
+    println!(
+        "== Difficulty {}/10 ==",
+        difficulty.score.num_format_sigdig(2)
+    );
+
+    if difficulty.best_len > 0 {
+        println!(
+            "  Best word length {}, {} {} of that length",
+            difficulty.best_len,
+            difficulty.best_count.num_format(),
+            if difficulty.best_count == 1 {
+                "word"
+            } else {
+                "words"
+            }
+        );
+        println!(
+            "  Obscurity {}%, drop off {}%",
+            (difficulty.obscurity * 100.0).round(),
+            (difficulty.drop_off * 100.0).round()
+        );
+    }
+}
+
+/// Prints numbers round solutions grouped by the count of numbers used
+pub fn print_solutions(solutions: &Solutions, count: usize) {
+    if !solutions.solutions.is_empty() && !solutions.is_exact() {
+        println!(
+            "No exact solution for {}, closest is {} away",
+            solutions.target, solutions.distance
+        );
+    }
+
+    println!(
+        "{} {} found",
+        solutions.solutions.len().num_format(),
+        if solutions.solutions.len() == 1 {
+            "solution"
+        } else {
+            "solutions"
+        }
+    );
+
+    let shown = if count == 0 {
+        solutions.solutions.len()
+    } else {
+        count.min(solutions.solutions.len())
+    };
+
+    // Group solutions by the number of numbers used
+    for group in solutions.solutions[..shown].chunk_by(|a, b| a.numbers_used() == b.numbers_used())
+    {
+        println!(
+            "== {} number solutions ({}) ==",
+            group[0].numbers_used(),
+            group.len()
+        );
+
+        for solution in group {
+            println!("  {solution}");
+        }
+    }
+
+    if shown < solutions.solutions.len() {
+        println!(
+            "{} more not shown",
+            (solutions.solutions.len() - shown).num_format()
+        );
+    }
+}
+
+/// Prints strings in padded columns to fit the terminal
+pub fn print_columns(items: &[String]) {
+    // Get terminal size
+    let term_width = terminal_width();
+
+    // Pad items to the longest
+    let width = items.iter().map(|i| i.len()).max().unwrap_or(0);
+
+    let cols = if term_width > 0 {
+        max(1, (term_width as usize - 1) / (width + 2))
+    } else {
+        1
+    };
+
+    for line in items.chunks(cols) {
+        let line = line
+            .iter()
+            .map(|i| format!("{i:width$}"))
+            .collect::<Vec<_>>();
+
+        println!("  {}", line.join("  ").trim_end())
+    }
+}
+
+fn print_word_count(count: usize) {
+    println!(
+        "{} {} found",
+        count.num_format(),
+        if count == 1 { "word" } else { "words" }
+    );
+}
+
 #[cfg(any(unix, windows))]
 fn terminal_width() -> u16 {
     if let Some((Width(w), _)) = terminal_size() {
@@ -0,0 +1,149 @@
+//! Vowel and consonant strategy simulation command
+
+use std::io;
+use std::time::Instant;
+
+use clap::Args as ClapArgs;
+use dictionary::{Dictionary, WordSizeConstraint};
+use numformat::NumFormat;
+use solver::{
+    simulate_split, time_seed, DrawError, LetterRules, PileKind, SimulationArgs, SplitStats,
+};
+
+use crate::{pile_frequencies, signature_index, solver_backend, Args};
+
+/// Number of letters in a Countdown draw
+const DRAW_LEN: usize = 9;
+
+/// Simulate command arguments
+#[derive(ClapArgs)]
+pub struct SimulateArgs {
+    /// Number of rounds to simulate for each vowel and consonant choice
+    #[clap(short = 'n', long = "rounds", default_value_t = 1000)]
+    rounds: usize,
+
+    /// Random number seed (the same seed gives the same draws)
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+}
+
+/// Simulates rounds for each Countdown vowel and consonant choice and prints a comparison
+pub fn simulate(args: &Args, simulate_args: &SimulateArgs) -> io::Result<()> {
+    // Load words up to the draw length
+    let mut size = WordSizeConstraint::default();
+
+    size.set_min(args.min_len as usize);
+    size.set_max(DRAW_LEN);
+
+    let dictionary = Dictionary::new_from_file(&args.dictionary_file, size, args.verbose)?;
+
+    let index = signature_index(args, &dictionary, args.verbose);
+
+    let seed = simulate_args.seed.unwrap_or_else(time_seed);
+
+    println!(
+        "Simulating {} rounds for each choice (seed {})",
+        simulate_args.rounds.num_format(),
+        seed
+    );
+
+    // Simulate each choice allowed by the Countdown rules
+    let start_time = Instant::now();
+
+    let rules = LetterRules::countdown();
+
+    let stats = (rules.min_vowels..=DRAW_LEN - rules.min_consonants)
+        .map(|vowels| {
+            simulate_split(&SimulationArgs {
+                piles: pile_frequencies(args),
+                rounds: simulate_args.rounds,
+                seed,
+                threads: args.threads.unwrap_or(0),
+                backend: solver_backend(&index),
+                ..SimulationArgs::new(&dictionary, vowels, DRAW_LEN - vowels)
+            })
+            .map_err(|e| (vowels, e))
+        })
+        .collect::<Result<Vec<_>, _>>();
+
+    let stats = match stats {
+        Ok(stats) => stats,
+        Err((vowels, DrawError::PileEmpty(kind))) => {
+            let needed = match kind {
+                PileKind::Vowel => vowels,
+                PileKind::Consonant => DRAW_LEN - vowels,
+            };
+
+            eprintln!("The {} pile has fewer than {needed} tiles", kind.name());
+            std::process::exit(1);
+        }
+        Err((_, e)) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+
+    if args.verbose {
+        println!(
+            "Simulation took {} seconds",
+            start_time.elapsed().as_secs_f64().num_format_sigdig(2)
+        );
+    }
+
+    print_comparison(&stats);
+
+    Ok(())
+}
+
+fn print_comparison(stats: &[SplitStats]) {
+    let names = stats
+        .iter()
+        .map(|s| format!("{}V{}C", s.vowels, s.consonants))
+        .collect::<Vec<_>>();
+
+    let row = |label: &str, values: Vec<String>| {
+        println!(
+            "  {label:<16}{}",
+            values.iter().map(|v| format!("{v:>8}")).collect::<String>()
+        );
+    };
+
+    println!("== Summary ==");
+    row("", names.clone());
+    row(
+        "Mean best length",
+        stats
+            .iter()
+            .map(|s| format!("{:.2}", s.mean_best_len()))
+            .collect(),
+    );
+    row(
+        "Chance of a nine",
+        stats.iter().map(|s| percent(s.full_chance())).collect(),
+    );
+    row(
+        "Expected score",
+        stats
+            .iter()
+            .map(|s| format!("{:.2}", s.expected_score()))
+            .collect(),
+    );
+
+    println!("== Best word length ==");
+    row("", names);
+
+    for len in (0..=DRAW_LEN).rev() {
+        if stats.iter().all(|s| s.len_chance(len) == 0.0) {
+            continue;
+        }
+
+        row(
+            &format!("{len} letters"),
+            stats.iter().map(|s| percent(s.len_chance(len))).collect(),
+        );
+    }
+}
+
+fn percent(proportion: f64) -> String {
+    format!("{:.1}%", proportion * 100.0)
+}
@@ -0,0 +1,155 @@
+//! History statistics command
+
+use std::io;
+
+use clap::{Args as ClapArgs, ValueEnum};
+use game::{history_stats, Period, RoundStats};
+use numformat::NumFormat;
+
+use crate::results::print_columns;
+use crate::{history, Args};
+
+/// Stats command arguments
+#[derive(ClapArgs)]
+pub struct StatsArgs {
+    /// Report statistics for the rounds in the history file
+    #[clap(long = "history", required = true)]
+    history: bool,
+
+    /// Period to group the rounds by
+    #[clap(long = "by", value_enum, default_value_t = GroupBy::Week)]
+    by: GroupBy,
+
+    /// Only include rounds played by this player
+    #[clap(long = "player")]
+    player: Option<String>,
+
+    /// Number of most missed words to show
+    #[clap(short = 'n', long = "missed", default_value_t = 20)]
+    missed: usize,
+}
+
+/// Periods to group rounds by
+#[derive(Clone, Copy, ValueEnum)]
+enum GroupBy {
+    /// Calendar day
+    Day,
+    /// Week starting on Monday
+    Week,
+    /// Calendar month
+    Month,
+}
+
+impl GroupBy {
+    fn period(&self) -> Period {
+        match self {
+            GroupBy::Day => Period::Day,
+            GroupBy::Week => Period::Week,
+            GroupBy::Month => Period::Month,
+        }
+    }
+}
+
+/// Prints statistics for the rounds in the history file
+pub fn stats(args: &Args, stats_args: &StatsArgs) -> io::Result<()> {
+    let Some(history) = history(args) else {
+        println!("History is disabled");
+        return Ok(());
+    };
+
+    let mut entries = history.load()?;
+
+    if let Some(player) = &stats_args.player {
+        entries.retain(|e| e.player.as_ref() == Some(player));
+    }
+
+    println!(
+        "{} {} in {}",
+        entries.len().num_format(),
+        if entries.len() == 1 {
+            "round"
+        } else {
+            "rounds"
+        },
+        history.path().display()
+    );
+
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let stats = history_stats(&entries, stats_args.by.period());
+
+    let row = |label: &str, values: [String; 4]| {
+        println!(
+            "  {label:<12}{}",
+            values
+                .iter()
+                .map(|v| format!("{v:>12}"))
+                .collect::<String>()
+        );
+    };
+
+    let stats_row = |stats: &RoundStats| {
+        row(
+            &stats.label,
+            [
+                stats.rounds.num_format(),
+                percent(stats.accuracy()),
+                format!("{:.2}", stats.mean_gap()),
+                format!("{:.2}", stats.mean_score()),
+            ],
+        )
+    };
+
+    let header = |label: &str| {
+        row(
+            label,
+            [
+                "Rounds".into(),
+                "Best found".into(),
+                "Mean gap".into(),
+                "Mean score".into(),
+            ],
+        )
+    };
+
+    println!("== Summary ==");
+    header("");
+    stats_row(&stats.overall);
+    println!(
+        "  Valid words {}, mean time {:.1} seconds",
+        percent(stats.overall.valid_rate()),
+        entries.iter().map(|e| e.seconds).sum::<f64>() / entries.len() as f64
+    );
+
+    println!("== Accuracy over time ==");
+    header(match stats_args.by {
+        GroupBy::Day => "Day",
+        GroupBy::Week => "Week",
+        GroupBy::Month => "Month",
+    });
+
+    for period in &stats.periods {
+        stats_row(period);
+    }
+
+    if !stats.missed.is_empty() {
+        println!("== Most missed words ==");
+
+        let missed = stats
+            .missed
+            .iter()
+            .take(stats_args.missed)
+            .map(|(word, count)| format!("{word} ({count})"))
+            .collect::<Vec<_>>();
+
+        print_columns(&missed);
+    }
+
+    Ok(())
+}
+
+fn percent(proportion: f64) -> String {
+    format!("{:.1}%", proportion * 100.0)
+}
@@ -0,0 +1,203 @@
+//! Two player game command
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args as ClapArgs;
+use game::{Entry, HistoryEntry, TwoPlayerGame, ROUND_TIME};
+use solver::{time_seed, LetterPiles, Rng};
+
+use crate::input::Input;
+use crate::play::{choose_letters, load_round_dictionary, spaced};
+use crate::{history, pile_frequencies, Args};
+
+/// Two player command arguments
+#[derive(ClapArgs)]
+pub struct TwoPlayerArgs {
+    /// Player names
+    #[clap(long = "players", num_args = 2, default_values = ["Player 1", "Player 2"])]
+    players: Vec<String>,
+
+    /// Number of rounds to play
+    #[clap(short = 'r', long = "rounds", default_value_t = 4)]
+    rounds: usize,
+
+    /// Random number seed
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+
+    /// Seconds allowed to find a word
+    #[clap(long = "time", default_value_t = ROUND_TIME.as_secs())]
+    time: u64,
+
+    /// Write the game record to a JSON file
+    #[clap(long = "json")]
+    json: Option<PathBuf>,
+}
+
+/// Plays a two player game on one terminal
+pub fn two_player(args: &Args, two_player_args: &TwoPlayerArgs) -> io::Result<()> {
+    let dictionary = load_round_dictionary(args)?;
+
+    let frequencies = pile_frequencies(args);
+    let mut rng = Rng::new(two_player_args.seed.unwrap_or_else(time_seed));
+
+    let input = Input::new();
+
+    let names = [
+        two_player_args.players[0].as_str(),
+        two_player_args.players[1].as_str(),
+    ];
+
+    let mut game = TwoPlayerGame::new(&dictionary, names);
+
+    let history = history(args);
+
+    // Set if the input ends before the last round
+    let mut stopped = false;
+
+    'rounds: for number in 1..=two_player_args.rounds {
+        let chooser = game.chooser();
+
+        println!("== Round {number} ==");
+        println!("{} chooses the letters", names[chooser]);
+
+        let mut round = game.new_round();
+        let mut piles = LetterPiles::new(&frequencies);
+
+        if !choose_letters(&mut round, &input, |round, kind| {
+            round.choose(kind, &mut piles, &mut rng)
+        }) {
+            stopped = true;
+            break;
+        }
+
+        println!("Letters: {}", spaced(round.letters()));
+        println!("Press enter to stop the clock early");
+
+        let time = Duration::from_secs(two_player_args.time);
+        let start = Instant::now();
+
+        input.clock(time);
+
+        let taken = start.elapsed().min(time);
+
+        // The chooser declares first
+        let order = [chooser, 1 - chooser];
+
+        let mut declared = [0; 2];
+
+        for player in order {
+            match declare_length(&input, names[player]) {
+                Some(len) => declared[player] = len,
+                None => {
+                    stopped = true;
+                    break 'rounds;
+                }
+            }
+        }
+
+        let mut words = [String::new(), String::new()];
+
+        for player in order {
+            if declared[player] > 0 {
+                match input.prompt(&format!("{}, your word: ", names[player])) {
+                    Some(word) => words[player] = word,
+                    None => {
+                        stopped = true;
+                        break 'rounds;
+                    }
+                }
+            }
+        }
+
+        let [word0, word1] = words;
+
+        let record = game.finish_round(
+            &round,
+            [
+                Entry::new(declared[0], &word0),
+                Entry::new(declared[1], &word1),
+            ],
+        );
+
+        for (name, entry) in names.iter().zip(&record.entries) {
+            match &entry.reason {
+                None => println!("{name}: {} scores {}", entry.word, entry.score),
+                Some(reason) if entry.word.is_empty() => println!("{name}: {reason}"),
+                Some(reason) => println!("{name}: {} is not allowed: {reason}", entry.word),
+            }
+        }
+
+        if !record.best.is_empty() {
+            println!("Best: {}", record.best.join(", "));
+        }
+
+        // Record the round for each player
+        if let Some(history) = &history {
+            for ((name, word), entry) in names.iter().zip([&word0, &word1]).zip(&record.entries) {
+                history.append(&HistoryEntry {
+                    player: Some(name.to_string()),
+                    valid: entry.valid,
+                    score: entry.score,
+                    ..HistoryEntry::new("two-player", &round, &round.declare(word), taken)
+                })?;
+            }
+        }
+
+        print_scores(&game);
+    }
+
+    if stopped {
+        println!();
+        println!(
+            "Game stopped after {} of {} rounds",
+            game.record().rounds.len(),
+            two_player_args.rounds
+        );
+        print_scores(&game);
+    }
+
+    // Final result
+    match game.leaders().as_slice() {
+        [winner] => println!("{} wins", names[*winner]),
+        _ => println!("It's a draw"),
+    }
+
+    if let Some(file) = &two_player_args.json {
+        let json = serde_json::to_string_pretty(game.record()).map_err(io::Error::other)?;
+
+        fs::write(file, json + "\n")?;
+    }
+
+    Ok(())
+}
+
+/// Asks a player for the length of their word. Returns None at the end of the input
+fn declare_length(input: &Input, name: &str) -> Option<usize> {
+    loop {
+        let answer = input.prompt(&format!("{name}, how many letters? "))?;
+
+        if answer.is_empty() {
+            return Some(0);
+        }
+
+        match answer.parse() {
+            Ok(len) => return Some(len),
+            Err(_) => println!("Please enter a number"),
+        }
+    }
+}
+
+/// Prints the scoreboard
+fn print_scores(game: &TwoPlayerGame) {
+    let scores = game
+        .players()
+        .iter()
+        .map(|p| format!("{} {}", p.name, p.score))
+        .collect::<Vec<_>>();
+
+    println!("Scores: {}", scores.join(", "));
+}
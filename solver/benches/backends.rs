@@ -0,0 +1,66 @@
+//! Compares the trie and signature index solver backends
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use dictionary::{Dictionary, WordSizeConstraint};
+use solver::{find_words, SignatureIndex, SolverArgs, SolverBackend, TrieBackend};
+
+const WORDS: &str = "../words.txt.gz";
+
+fn backends(c: &mut Criterion) {
+    let mut size = WordSizeConstraint::default();
+
+    size.set_min(3);
+    size.set_max(9);
+
+    let dictionary = Dictionary::new_from_file(WORDS, size, false).unwrap();
+
+    // Building the index is a one off cost
+    c.bench_function("index build", |b| {
+        b.iter(|| SignatureIndex::new(&dictionary))
+    });
+
+    let index = SignatureIndex::new(&dictionary);
+
+    let backends: [&dyn SolverBackend; 2] = [&TrieBackend, &index];
+
+    let mut group = c.benchmark_group("solve");
+
+    for letters in ["RUSTY", "ANEDRWIPS", "EEEEEEEEE", "AEIOURSTL"] {
+        for backend in backends {
+            group.bench_with_input(
+                BenchmarkId::new(backend.name(), letters),
+                letters,
+                |b, l| {
+                    b.iter(|| {
+                        find_words(SolverArgs {
+                            backend,
+                            ..SolverArgs::new(l, &dictionary)
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    // Reusing letters makes the index scan every signature
+    for backend in backends {
+        group.bench_with_input(
+            BenchmarkId::new(backend.name(), "ANEDRWIPS reuse"),
+            "ANEDRWIPS",
+            |b, l| {
+                b.iter(|| {
+                    find_words(SolverArgs {
+                        reuse_letters: true,
+                        backend,
+                        ..SolverArgs::new(l, &dictionary)
+                    })
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, backends);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+//! Solver backends
+
+use crate::{trie_search, SearchOutcome, SolverArgs};
+
+/// A word search implementation
+pub trait SolverBackend: Sync {
+    /// Returns the name of the backend
+    fn name(&self) -> &'static str;
+
+    /// Finds the words which can be made from the letters in the arguments
+    fn search(&self, args: &SolverArgs) -> SearchOutcome;
+}
+
+/// Searches by walking the dictionary tree. Supports threads and tracing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrieBackend;
+
+impl SolverBackend for TrieBackend {
+    fn name(&self) -> &'static str {
+        "trie"
+    }
+
+    fn search(&self, args: &SolverArgs) -> SearchOutcome {
+        trie_search(args)
+    }
+}
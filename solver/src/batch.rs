@@ -0,0 +1,191 @@
+//! Batch solving of many letter sets
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use dictionary::Dictionary;
+
+use crate::backend::{SolverBackend, TrieBackend};
+use crate::counts::LetterCounts;
+use crate::letters::{normalise_letters, LetterError, LetterRules};
+use crate::{find_words, SolverArgs};
+
+/// Arguments for batch solving
+pub struct BatchArgs<'a> {
+    /// Dictionary to use
+    pub dictionary: &'a Dictionary,
+    /// Rules to validate each letter set with
+    pub rules: LetterRules,
+    /// Letters can be reused flag
+    pub reuse_letters: bool,
+    /// Number of threads to solve with (0 to use all available cores)
+    pub threads: usize,
+    /// Search implementation to use
+    pub backend: &'a dyn SolverBackend,
+}
+
+impl<'a> BatchArgs<'a> {
+    /// Creates batch arguments with default options
+    pub fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            dictionary,
+            rules: LetterRules::default(),
+            reuse_letters: false,
+            threads: 1,
+            backend: &TrieBackend,
+        }
+    }
+}
+
+/// Result of solving one letter set in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResult {
+    /// Input line
+    pub input: String,
+    /// Words found in alphabetical order, or the reason the letters are invalid
+    pub words: Result<Vec<String>, LetterError>,
+}
+
+/// Results of a batch solve
+#[derive(Debug, Clone)]
+pub struct BatchOutcome {
+    /// Result for each input line in input order
+    pub results: Vec<BatchResult>,
+    /// Number of distinct letter sets solved. Repeated and permuted letter sets are only
+    /// solved once
+    pub solved: usize,
+}
+
+/// Solves many letter sets, one per input line. Letter sets with the same letters in any order
+/// are only solved once, and distinct letter sets are solved in parallel
+pub fn solve_batch<S: AsRef<str>>(lines: &[S], args: &BatchArgs) -> BatchOutcome {
+    // Validate each line and find the distinct letter sets
+    let mut distinct = HashMap::new();
+    let mut sets = Vec::new();
+
+    let keys = lines
+        .iter()
+        .map(|line| {
+            normalise_letters(line.as_ref(), &args.rules).map(|letters| {
+                let counts = LetterCounts::from_letters(&letters);
+
+                *distinct.entry(counts).or_insert_with(|| {
+                    sets.push(counts.to_string());
+                    sets.len() - 1
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Solve the distinct letter sets
+    let solved = solve_sets(&sets, args);
+
+    let results = lines
+        .iter()
+        .zip(keys)
+        .map(|(line, key)| BatchResult {
+            input: line.as_ref().to_string(),
+            words: key.map(|i| solved[i].clone()),
+        })
+        .collect();
+
+    BatchOutcome {
+        results,
+        solved: sets.len(),
+    }
+}
+
+/// Solves letter sets using a pool of threads
+fn solve_sets(sets: &[String], args: &BatchArgs) -> Vec<Vec<String>> {
+    let solve = |letters: &str| {
+        find_words(SolverArgs {
+            reuse_letters: args.reuse_letters,
+            backend: args.backend,
+            ..SolverArgs::new(letters, args.dictionary)
+        })
+    };
+
+    let threads = if args.threads == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        args.threads
+    };
+
+    if threads <= 1 {
+        return sets.iter().map(|letters| solve(letters)).collect();
+    }
+
+    // Next letter set to solve
+    let next_set = AtomicUsize::new(0);
+
+    let results = Mutex::new(vec![Vec::new(); sets.len()]);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(sets.len()) {
+            scope.spawn(|| loop {
+                let set = next_set.fetch_add(1, Ordering::Relaxed);
+
+                if set >= sets.len() {
+                    break;
+                }
+
+                let words = solve(&sets[set]);
+
+                results.lock().unwrap()[set] = words;
+            });
+        }
+    });
+
+    results.into_inner().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch() {
+        let dictionary =
+            Dictionary::new_from_string("rust\nrut\nruts\ntsar", Default::default(), false)
+                .unwrap();
+
+        let lines = ["rust", "TRUS", "ratS", "ru5t", "star", "urts"];
+
+        for threads in [1, 3] {
+            let outcome = solve_batch(
+                &lines,
+                &BatchArgs {
+                    threads,
+                    ..BatchArgs::new(&dictionary)
+                },
+            );
+
+            assert_eq!(outcome.solved, 2);
+
+            let words = outcome
+                .results
+                .iter()
+                .map(|r| r.words.clone().map(|w| w.join(" ")))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                words,
+                vec![
+                    Ok("RUST RUT RUTS".into()),
+                    Ok("RUST RUT RUTS".into()),
+                    Ok("TSAR".into()),
+                    Err(LetterError::InvalidCharacter {
+                        position: 3,
+                        character: '5'
+                    }),
+                    Ok("TSAR".into()),
+                    Ok("RUST RUT RUTS".into()),
+                ]
+            );
+
+            assert_eq!(outcome.results[1].input, "TRUS");
+        }
+    }
+}
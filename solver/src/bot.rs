@@ -0,0 +1,137 @@
+//! Computer opponent
+
+use dictionary::Dictionary;
+
+use crate::frequency::{obscurity, WordFrequencies};
+use crate::rng::Rng;
+use crate::{find_words, SolverArgs};
+
+/// Bot skill levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Skill {
+    /// Often misses long and obscure words
+    Beginner,
+    /// Usually finds a good word but misses some long or obscure ones
+    Average,
+    /// Rarely misses a word
+    Expert,
+    /// Always finds the best word
+    Perfect,
+}
+
+impl Skill {
+    /// Returns the name of the skill level
+    pub fn name(&self) -> &'static str {
+        match self {
+            Skill::Beginner => "beginner",
+            Skill::Average => "average",
+            Skill::Expert => "expert",
+            Skill::Perfect => "perfect",
+        }
+    }
+
+    /// Returns the chance of spotting a four letter word, the proportion of that chance kept for
+    /// each extra letter, and how much obscurity reduces the chance
+    fn params(&self) -> (f64, f64, f64) {
+        match self {
+            Skill::Beginner => (0.5, 0.6, 0.9),
+            Skill::Average => (0.7, 0.8, 0.7),
+            Skill::Expert => (0.9, 0.92, 0.4),
+            Skill::Perfect => (1.0, 1.0, 0.0),
+        }
+    }
+}
+
+/// A computer player which declares words the way a person of a given skill level might. Each
+/// word which can be made is spotted with a chance which falls for longer and more obscure words,
+/// and the longest word spotted is declared, preferring the most common
+pub struct Bot<'a> {
+    /// Skill level
+    pub skill: Skill,
+    /// Word usage frequencies used to judge how obscure words are
+    pub frequencies: Option<&'a WordFrequencies>,
+}
+
+impl<'a> Bot<'a> {
+    /// Creates a bot with a skill level and no word frequencies
+    pub fn new(skill: Skill) -> Self {
+        Self {
+            skill,
+            frequencies: None,
+        }
+    }
+
+    /// Returns the chance of the bot spotting a word (upper case)
+    pub fn spot_chance(&self, word: &str) -> f64 {
+        let (base, decay, obscurity_weight) = self.skill.params();
+
+        let length = decay.powi(word.len().saturating_sub(4) as i32);
+        let familiarity = 1.0 - obscurity_weight * obscurity(word, self.frequencies);
+
+        base * length * familiarity
+    }
+
+    /// Chooses a word to declare from the words which can be made (upper case). Returns None if
+    /// the bot doesn't spot any word
+    pub fn declare<S: AsRef<str>>(&self, words: &[S], rng: &mut Rng) -> Option<String> {
+        words
+            .iter()
+            .map(|w| w.as_ref())
+            .filter(|w| rng.next_f64() < self.spot_chance(w))
+            .max_by(|a, b| {
+                a.len().cmp(&b.len()).then_with(|| {
+                    obscurity(b, self.frequencies).total_cmp(&obscurity(a, self.frequencies))
+                })
+            })
+            .map(|w| w.to_string())
+    }
+
+    /// Finds the words which can be made from the letters and chooses one to declare
+    pub fn play(&self, letters: &str, dictionary: &Dictionary, rng: &mut Rng) -> Option<String> {
+        self.declare(&find_words(SolverArgs::new(letters, dictionary)), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bots() {
+        let words = ["EAT", "SEAT", "TEAS", "EATS", "SEATER", "ZYXTAS"];
+
+        // A perfect bot always finds the longest, most common word
+        let frequencies = "SEAT 10\nSEATER 5\nZYXTAS 1".parse().unwrap();
+
+        let perfect = Bot {
+            frequencies: Some(&frequencies),
+            ..Bot::new(Skill::Perfect)
+        };
+
+        assert_eq!(
+            perfect.declare(&words, &mut Rng::new(1)),
+            Some("SEATER".into())
+        );
+        assert_eq!(perfect.declare::<&str>(&[], &mut Rng::new(1)), None);
+
+        // Weaker bots find shorter words on average
+        let mean_len = |skill| {
+            let bot = Bot::new(skill);
+            let mut rng = Rng::new(3);
+
+            (0..1000)
+                .map(|_| bot.declare(&words, &mut rng).map_or(0, |w| w.len()))
+                .sum::<usize>()
+        };
+
+        assert!(mean_len(Skill::Beginner) < mean_len(Skill::Average));
+        assert!(mean_len(Skill::Average) < mean_len(Skill::Expert));
+        assert!(mean_len(Skill::Expert) < mean_len(Skill::Perfect));
+
+        // Obscure words are harder to spot
+        let bot = Bot::new(Skill::Average);
+
+        assert!(bot.spot_chance("SEATER") > bot.spot_chance("ZYXTAS"));
+        assert!(bot.spot_chance("SEAT") > bot.spot_chance("SEATER"));
+    }
+}
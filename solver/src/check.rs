@@ -0,0 +1,197 @@
+//! Word checking
+
+use std::error::Error;
+use std::fmt;
+
+use dictionary::Dictionary;
+
+use crate::counts::LetterCounts;
+
+/// Reasons a word is not allowed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordError {
+    /// The word contains a character which is not a letter
+    InvalidCharacter(char),
+    /// The word is shorter than the minimum length
+    TooShort {
+        /// Length of the word
+        len: usize,
+        /// Minimum word length
+        min: usize,
+    },
+    /// The word uses letters which are not available
+    UnavailableLetters {
+        /// Letters in the word which are not in the letters at all
+        missing: String,
+        /// Letters in the word used more times than they appear in the letters
+        overused: String,
+    },
+    /// The word is not in the dictionary
+    NotInDictionary,
+}
+
+impl fmt::Display for WordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordError::InvalidCharacter(c) => write!(f, "'{c}' is not a letter"),
+            WordError::TooShort { len, min } => {
+                write!(f, "Words must have at least {min} letters ({len} given)")
+            }
+            WordError::UnavailableLetters { missing, overused } => {
+                let mut reasons = Vec::new();
+
+                if !missing.is_empty() {
+                    reasons.push(format!("{} not available", list_letters(missing)));
+                }
+
+                if !overused.is_empty() {
+                    reasons.push(format!("{} used too many times", list_letters(overused)));
+                }
+
+                write!(f, "{}", reasons.join(", "))
+            }
+            WordError::NotInDictionary => write!(f, "Not in the dictionary"),
+        }
+    }
+}
+
+impl Error for WordError {}
+
+/// Checks a word can be made from the letters (upper case A-Z) without reusing letters and is in
+/// the dictionary. The word is converted to upper case. Returns the upper case word if allowed
+pub fn check_word(
+    word: &str,
+    letters: &str,
+    dictionary: &Dictionary,
+    min_len: usize,
+) -> Result<String, WordError> {
+    match find_word_errors(word, letters, dictionary, min_len)
+        .into_iter()
+        .next()
+    {
+        Some(e) => Err(e),
+        None => Ok(word.to_ascii_uppercase()),
+    }
+}
+
+/// Returns every reason a word is not allowed, or an empty vector if it is allowed. If the word
+/// contains a character which is not a letter no other checks are made
+pub fn find_word_errors(
+    word: &str,
+    letters: &str,
+    dictionary: &Dictionary,
+    min_len: usize,
+) -> Vec<WordError> {
+    if let Some(c) = word.chars().find(|c| !c.is_ascii_alphabetic()) {
+        return vec![WordError::InvalidCharacter(c)];
+    }
+
+    let word = word.to_ascii_uppercase();
+
+    let mut errors = Vec::new();
+
+    if word.len() < min_len {
+        errors.push(WordError::TooShort {
+            len: word.len(),
+            min: min_len,
+        });
+    }
+
+    // Check the letters are available
+    let available = LetterCounts::from_letters(letters);
+    let unavailable = available.missing(&LetterCounts::from_letters(&word));
+
+    if !unavailable.is_empty() {
+        let (missing, overused): (Vec<_>, Vec<_>) = unavailable
+            .iter()
+            .partition(|(c, _)| available.get(*c as u8 - b'A') == 0);
+
+        let letters = |counts: Vec<(char, u8)>| counts.into_iter().map(|(c, _)| c).collect();
+
+        errors.push(WordError::UnavailableLetters {
+            missing: letters(missing),
+            overused: letters(overused),
+        });
+    }
+
+    if !dictionary.contains(&word) {
+        errors.push(WordError::NotInDictionary);
+    }
+
+    errors
+}
+
+/// Formats letters as a list, eg. "A, B and C"
+fn list_letters(letters: &str) -> String {
+    let letters = letters.chars().map(|c| c.to_string()).collect::<Vec<_>>();
+
+    match letters.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        _ => letters.concat(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let dictionary =
+            Dictionary::new_from_string("rust\nrut\nruts\ntrust", Default::default(), false)
+                .unwrap();
+
+        assert_eq!(
+            check_word("rust", "SUTRA", &dictionary, 3),
+            Ok("RUST".into())
+        );
+        assert_eq!(
+            check_word("ru", "SUTRA", &dictionary, 3),
+            Err(WordError::TooShort { len: 2, min: 3 })
+        );
+        assert_eq!(
+            check_word("ru-t", "SUTRA", &dictionary, 3),
+            Err(WordError::InvalidCharacter('-'))
+        );
+        assert_eq!(
+            check_word("trust", "SUTRA", &dictionary, 3),
+            Err(WordError::UnavailableLetters {
+                missing: "".into(),
+                overused: "T".into()
+            })
+        );
+        assert_eq!(
+            check_word("tarts", "SUXRA", &dictionary, 3),
+            Err(WordError::UnavailableLetters {
+                missing: "T".into(),
+                overused: "".into()
+            })
+        );
+        assert_eq!(
+            check_word("tsar", "SUTRA", &dictionary, 3),
+            Err(WordError::NotInDictionary)
+        );
+
+        assert_eq!(
+            find_word_errors("tsxa", "SUTRA", &dictionary, 5),
+            vec![
+                WordError::TooShort { len: 4, min: 5 },
+                WordError::UnavailableLetters {
+                    missing: "X".into(),
+                    overused: "".into()
+                },
+                WordError::NotInDictionary
+            ]
+        );
+        assert!(find_word_errors("rust", "SUTRA", &dictionary, 3).is_empty());
+
+        assert_eq!(
+            WordError::UnavailableLetters {
+                missing: "XY".into(),
+                overused: "ABC".into()
+            }
+            .to_string(),
+            "X and Y not available, A, B and C used too many times"
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Countdown conundrum generator
+
+use std::collections::HashMap;
+
+use dictionary::Dictionary;
+
+use crate::phrase::{find_phrases, PhraseArgs};
+use crate::rng::Rng;
+
+/// Number of letters in a conundrum
+pub const CONUNDRUM_LEN: usize = 9;
+
+/// Minimum length of each word in a conundrum scramble
+const SCRAMBLE_MIN_WORD_LEN: usize = 3;
+
+/// A conundrum puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conundrum {
+    /// The answer
+    pub answer: String,
+    /// Words of the scrambled letters
+    pub scramble: Vec<String>,
+    /// Difficulty rating (1 = easiest, 5 = hardest)
+    pub difficulty: u8,
+}
+
+impl Conundrum {
+    /// Returns the scrambled letters with no spaces
+    pub fn letters(&self) -> String {
+        self.scramble.concat()
+    }
+}
+
+/// Generates conundrums from a dictionary
+pub struct ConundrumGenerator<'a> {
+    dictionary: &'a Dictionary,
+    answers: Vec<String>,
+}
+
+impl<'a> ConundrumGenerator<'a> {
+    /// Creates a conundrum generator finding all nine letter words in the dictionary which have
+    /// no other nine letter anagram
+    pub fn new(dictionary: &'a Dictionary) -> Self {
+        // Group nine letter words by their sorted letters
+        let mut groups: HashMap<Vec<u8>, Vec<String>> = HashMap::new();
+
+        for word in dictionary.words_with_len(CONUNDRUM_LEN) {
+            let mut key = word.clone().into_bytes();
+            key.sort_unstable();
+
+            groups.entry(key).or_default().push(word);
+        }
+
+        // Keep words with a unique anagram
+        let mut answers = groups
+            .into_values()
+            .filter(|words| words.len() == 1)
+            .flatten()
+            .collect::<Vec<_>>();
+
+        answers.sort();
+
+        Self {
+            dictionary,
+            answers,
+        }
+    }
+
+    /// Returns the possible conundrum answers in alphabetical order
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Returns the scrambles for an answer. Each scramble is a phrase of two or more words using
+    /// all of the letters of the answer. Scrambles with the fewest words are returned first
+    pub fn scrambles(&self, answer: &str) -> Vec<Vec<String>> {
+        find_phrases(PhraseArgs {
+            letters: answer,
+            dictionary: self.dictionary,
+            enumeration: &[],
+            min_len: SCRAMBLE_MIN_WORD_LEN,
+        })
+        .into_iter()
+        .filter(|phrase| phrase.len() > 1)
+        .collect()
+    }
+
+    /// Generates a random conundrum. Returns None if no conundrum can be made from the dictionary.
+    /// Scrambles with the fewest words are preferred
+    pub fn generate(&self, rng: &mut Rng) -> Option<Conundrum> {
+        if self.answers.is_empty() {
+            return None;
+        }
+
+        // Try answers from a random starting point
+        let start = rng.below(self.answers.len());
+
+        for i in 0..self.answers.len() {
+            let answer = &self.answers[(start + i) % self.answers.len()];
+
+            let scrambles = self.scrambles(answer);
+
+            let Some(fewest) = scrambles.first().map(|s| s.len()) else {
+                continue;
+            };
+
+            let best = scrambles
+                .iter()
+                .filter(|s| s.len() == fewest)
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let scramble = rng.choose(&best)?.clone();
+
+            return Some(Conundrum {
+                difficulty: rate_difficulty(answer, &scramble.concat()),
+                answer: answer.clone(),
+                scramble,
+            });
+        }
+
+        None
+    }
+}
+
+/// Rates the difficulty of a conundrum from 1 (easiest) to 5 (hardest). Scrambles which leave
+/// letters in their answer positions or keep runs of the answer's letters together are easier
+pub fn rate_difficulty(answer: &str, letters: &str) -> u8 {
+    let answer = answer.as_bytes();
+    let letters = letters.as_bytes();
+
+    // Count letters in the same position
+    let same_pos = answer
+        .iter()
+        .zip(letters.iter())
+        .filter(|(a, b)| a == b)
+        .count();
+
+    // Find the longest run of the answer's letters in the scramble
+    let mut longest_run = 0;
+
+    for i in 0..answer.len() {
+        for j in 0..letters.len() {
+            let run = answer[i..]
+                .iter()
+                .zip(letters[j..].iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            longest_run = longest_run.max(run);
+        }
+    }
+
+    // Single letter runs give nothing away
+    let clues = same_pos + if longest_run > 1 { longest_run } else { 0 };
+
+    let ease = (clues as f64 / answer.len().max(1) as f64).min(1.0);
+
+    5 - (ease * 4.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DICT: &str = "\
+        horse\n\
+        moan\n\
+        mahseer\n\
+        monarchs\n\
+        moonshare\n\
+        nor\n\
+        searchmon\n\
+        shore\n\
+        ";
+
+    #[test]
+    fn generator() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let generator = ConundrumGenerator::new(&dictionary);
+
+        assert_eq!(generator.answers(), &["MOONSHARE", "SEARCHMON"]);
+
+        assert_eq!(
+            generator.scrambles("MOONSHARE"),
+            vec![vec!["HORSE", "MOAN"], vec!["MOAN", "SHORE"]]
+        );
+        assert!(generator.scrambles("SEARCHMON").is_empty());
+
+        let conundrum = generator.generate(&mut Rng::new(1)).unwrap();
+
+        assert_eq!(conundrum.answer, "MOONSHARE");
+        assert_eq!(conundrum.letters().len(), 9);
+        assert!((1..=5).contains(&conundrum.difficulty));
+    }
+
+    #[test]
+    fn difficulty() {
+        assert_eq!(rate_difficulty("COUNTDOWN", "COUNTDOWN"), 1);
+        assert_eq!(rate_difficulty("COUNTDOWN", "WONCUTDON"), 3);
+        assert_eq!(rate_difficulty("COUNTDOWN", "NWOCDNOTU"), 5);
+    }
+}
@@ -0,0 +1,164 @@
+//! Letter multisets
+
+use std::fmt;
+
+use crate::letters::is_vowel;
+
+/// Maximum count of each letter which can be held
+pub const MAX_LETTER_COUNT: usize = u8::MAX as usize;
+
+/// Count of each letter A-Z in a set of letters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LetterCounts {
+    counts: [u8; 26],
+}
+
+impl LetterCounts {
+    /// Creates an empty set of letter counts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts the letters in a string (must be upper case A-Z)
+    pub fn from_letters(letters: &str) -> Self {
+        let mut counts = Self::new();
+
+        for c in letters.bytes() {
+            counts.add(c - b'A');
+        }
+
+        counts
+    }
+
+    /// Returns the count of a letter element (0 = A, 25 = Z)
+    #[inline]
+    pub fn get(&self, letter: u8) -> u8 {
+        self.counts[letter as usize]
+    }
+
+    /// Adds a letter element (0 = A, 25 = Z). Counts saturate at `MAX_LETTER_COUNT`
+    #[inline]
+    pub fn add(&mut self, letter: u8) {
+        let count = &mut self.counts[letter as usize];
+
+        *count = count.saturating_add(1);
+    }
+
+    /// Removes a letter element (0 = A, 25 = Z). Returns false if the letter is not present
+    #[inline]
+    pub fn remove(&mut self, letter: u8) -> bool {
+        let count = &mut self.counts[letter as usize];
+
+        if *count == 0 {
+            false
+        } else {
+            *count -= 1;
+            true
+        }
+    }
+
+    /// Returns the total number of letters
+    pub fn len(&self) -> usize {
+        self.counts.iter().map(|c| *c as usize).sum()
+    }
+
+    /// Returns true if there are no letters
+    pub fn is_empty(&self) -> bool {
+        self.counts.iter().all(|c| *c == 0)
+    }
+
+    /// Returns true if every letter in `other` is available in this set
+    pub fn contains(&self, other: &LetterCounts) -> bool {
+        self.counts
+            .iter()
+            .zip(other.counts.iter())
+            .all(|(a, b)| a >= b)
+    }
+
+    /// Returns the letters in `other` which are not available in this set
+    pub fn missing(&self, other: &LetterCounts) -> LetterCounts {
+        let mut missing = LetterCounts::new();
+
+        for (i, (a, b)) in self.counts.iter().zip(other.counts.iter()).enumerate() {
+            missing.counts[i] = b.saturating_sub(*a);
+        }
+
+        missing
+    }
+
+    /// Returns the vowels in the set
+    pub fn vowels(&self) -> LetterCounts {
+        self.filter(is_vowel)
+    }
+
+    /// Returns the consonants in the set
+    pub fn consonants(&self) -> LetterCounts {
+        self.filter(|c| !is_vowel(c))
+    }
+
+    /// Returns an iterator over the letters in the set and their counts in alphabetical order.
+    /// Letters with a zero count are skipped
+    pub fn iter(&self) -> impl Iterator<Item = (char, u8)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c > 0)
+            .map(|(i, c)| ((b'A' + i as u8) as char, *c))
+    }
+
+    fn filter(&self, f: impl Fn(char) -> bool) -> LetterCounts {
+        let mut result = LetterCounts::new();
+
+        for (i, count) in self.counts.iter().enumerate() {
+            if f((b'A' + i as u8) as char) {
+                result.counts[i] = *count;
+            }
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for LetterCounts {
+    /// Formats the letters in alphabetical order
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (c, count) in self.iter() {
+            for _ in 0..count {
+                write!(f, "{c}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts() {
+        let mut counts = LetterCounts::from_letters("COUNTDOWN");
+
+        assert_eq!(counts.len(), 9);
+        assert_eq!(counts.get(b'O' - b'A'), 2);
+        assert_eq!(counts.to_string(), "CDNNOOTUW");
+        assert_eq!(counts.vowels().to_string(), "OOU");
+        assert_eq!(counts.consonants().to_string(), "CDNNTW");
+
+        let word = LetterCounts::from_letters("COTTON");
+
+        assert!(!counts.contains(&word));
+        assert_eq!(counts.missing(&word).to_string(), "T");
+
+        assert!(counts.remove(b'W' - b'A'));
+        assert!(!counts.remove(b'W' - b'A'));
+        assert!(counts.contains(&LetterCounts::from_letters("COUNT")));
+
+        assert!(LetterCounts::new().is_empty());
+
+        let many = LetterCounts::from_letters(&"A".repeat(300));
+
+        assert_eq!(many.len(), MAX_LETTER_COUNT);
+    }
+}
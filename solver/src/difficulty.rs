@@ -0,0 +1,121 @@
+//! Letter set difficulty rating
+
+use crate::frequency::{obscurity, WordFrequencies};
+use crate::{find_words, SolverArgs};
+
+/// Difficulty rating of a letter set, derived from the words which can be made
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difficulty {
+    /// Length of the best word
+    pub best_len: usize,
+    /// Number of words of the best length
+    pub best_count: usize,
+    /// Mean obscurity of the best words (0.0 to 1.0)
+    pub obscurity: f64,
+    /// How steeply the word counts drop off at the best length (0.0 to 1.0). This is high when
+    /// there are many words one letter shorter than the best
+    pub drop_off: f64,
+    /// Overall difficulty from 0.0 (easy) to 10.0 (hard)
+    pub score: f64,
+}
+
+impl Difficulty {
+    /// Rates the difficulty of finding the best word from the words which can be made from
+    /// `letters` letters. Long best words are harder to find, as are best words which are rare,
+    /// obscure, or stand out from many shorter words
+    pub fn from_words<S: AsRef<str>>(
+        words: &[S],
+        letters: usize,
+        frequencies: Option<&WordFrequencies>,
+    ) -> Self {
+        let best_len = words.iter().map(|w| w.as_ref().len()).max().unwrap_or(0);
+
+        if best_len == 0 || letters == 0 {
+            return Self {
+                best_len: 0,
+                best_count: 0,
+                obscurity: 0.0,
+                drop_off: 0.0,
+                score: 0.0,
+            };
+        }
+
+        let count_len = |len| words.iter().filter(|w| w.as_ref().len() == len).count();
+
+        let best_count = count_len(best_len);
+        let shorter_count = count_len(best_len - 1);
+
+        let obscurity = words
+            .iter()
+            .map(|w| w.as_ref())
+            .filter(|w| w.len() == best_len)
+            .map(|w| obscurity(w, frequencies))
+            .sum::<f64>()
+            / best_count as f64;
+
+        let drop_off = shorter_count as f64 / (best_count + shorter_count) as f64;
+
+        // Weighted components, each from 0.0 to 1.0
+        let length = (best_len as f64 / letters as f64).min(1.0);
+        let scarcity = 1.0 / best_count as f64;
+
+        let score = 10.0 * (0.3 * length + 0.25 * scarcity + 0.25 * obscurity + 0.2 * drop_off);
+
+        Self {
+            best_len,
+            best_count,
+            obscurity,
+            drop_off,
+            score,
+        }
+    }
+}
+
+/// Finds the words which can be made from the letters and rates the difficulty of the letter set
+pub fn rate_letters(args: SolverArgs, frequencies: Option<&WordFrequencies>) -> Difficulty {
+    let letters = args.letters.len();
+
+    Difficulty::from_words(&find_words(args), letters, frequencies)
+}
+
+#[cfg(test)]
+mod tests {
+    use dictionary::Dictionary;
+
+    use super::*;
+
+    #[test]
+    fn difficulty() {
+        let none = Difficulty::from_words::<&str>(&[], 9, None);
+
+        assert_eq!(none.score, 0.0);
+
+        let easy = Difficulty::from_words(&["EAT", "TEA", "ATE", "SEAT", "EATS", "TEAS"], 9, None);
+        let hard =
+            Difficulty::from_words(&["EAT", "TEA", "ATE", "SEAT", "EATS", "ZEATSX"], 9, None);
+
+        assert_eq!(easy.best_len, 4);
+        assert_eq!(easy.best_count, 3);
+        assert_eq!(easy.drop_off, 0.5);
+        assert_eq!(hard.best_len, 6);
+        assert_eq!(hard.best_count, 1);
+        assert_eq!(hard.drop_off, 0.0);
+        assert!(hard.obscurity > easy.obscurity);
+        assert!(hard.score > easy.score);
+        assert!(hard.score <= 10.0);
+
+        // Word frequencies are used for obscurity when given
+        let frequencies = "SEAT 100\nEATS 100\nTEAS 1".parse().unwrap();
+        let rated = Difficulty::from_words(&["SEAT", "EATS", "TEAS"], 9, Some(&frequencies));
+
+        assert!(rated.obscurity > 0.28 && rated.obscurity < 0.29);
+
+        let dictionary =
+            Dictionary::new_from_string("eat\ntea\nseat", Default::default(), false).unwrap();
+
+        assert_eq!(
+            rate_letters(SolverArgs::new("SEAT", &dictionary), None).best_len,
+            4
+        );
+    }
+}
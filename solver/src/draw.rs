@@ -0,0 +1,491 @@
+//! Letter draw probabilities
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::counts::LetterCounts;
+
+/// Number of tiles of each letter in a letter pile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterFrequencies {
+    counts: [u32; 26],
+}
+
+impl LetterFrequencies {
+    /// Creates letter frequencies from a list of upper case letters and tile counts
+    pub fn new(frequencies: &[(char, u32)]) -> Self {
+        let mut counts = [0; 26];
+
+        for (c, count) in frequencies {
+            counts[(*c as u8 - b'A') as usize] += count;
+        }
+
+        Self { counts }
+    }
+
+    /// Returns the number of tiles of a letter (upper case A-Z)
+    pub fn get(&self, letter: char) -> u32 {
+        self.counts[(letter as u8 - b'A') as usize]
+    }
+
+    /// Returns the total number of tiles
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns an iterator over the letters and tile counts in alphabetical order.
+    /// Letters with no tiles are skipped
+    pub fn iter(&self) -> impl Iterator<Item = (char, u32)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c > 0)
+            .map(|(i, c)| ((b'A' + i as u8) as char, *c))
+    }
+}
+
+impl FromStr for LetterFrequencies {
+    type Err = FrequencyError;
+
+    /// Parses letter frequencies from a list of letters followed by tile counts separated by
+    /// white space or commas, eg. "A15 E21 I13 O13 U5"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut frequencies = Vec::new();
+
+        for token in s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|t| !t.is_empty())
+        {
+            let error = || FrequencyError {
+                token: token.to_string(),
+            };
+
+            let mut chars = token.chars();
+
+            let letter = chars
+                .next()
+                .filter(|c| c.is_ascii_alphabetic())
+                .ok_or_else(error)?
+                .to_ascii_uppercase();
+
+            let count = chars.as_str().parse::<u32>().map_err(|_| error())?;
+
+            frequencies.push((letter, count));
+        }
+
+        Ok(Self::new(&frequencies))
+    }
+}
+
+/// Letter frequency parsing error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrequencyError {
+    /// The invalid entry
+    pub token: String,
+}
+
+impl fmt::Display for FrequencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid letter frequency '{}', expected a letter followed by a tile count, eg. E21",
+            self.token
+        )
+    }
+}
+
+impl Error for FrequencyError {}
+
+/// Letter frequencies of the vowel and consonant piles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PileFrequencies {
+    /// Vowel pile
+    pub vowels: LetterFrequencies,
+    /// Consonant pile
+    pub consonants: LetterFrequencies,
+}
+
+impl PileFrequencies {
+    /// Countdown pile frequencies
+    pub fn countdown() -> Self {
+        Self {
+            vowels: LetterFrequencies::new(&[('A', 15), ('E', 21), ('I', 13), ('O', 13), ('U', 5)]),
+            consonants: LetterFrequencies::new(&[
+                ('B', 2),
+                ('C', 3),
+                ('D', 6),
+                ('F', 2),
+                ('G', 3),
+                ('H', 2),
+                ('J', 1),
+                ('K', 1),
+                ('L', 5),
+                ('M', 4),
+                ('N', 8),
+                ('P', 4),
+                ('Q', 1),
+                ('R', 9),
+                ('S', 9),
+                ('T', 9),
+                ('V', 1),
+                ('W', 1),
+                ('X', 1),
+                ('Y', 1),
+                ('Z', 1),
+            ]),
+        }
+    }
+}
+
+impl Default for PileFrequencies {
+    fn default() -> Self {
+        Self::countdown()
+    }
+}
+
+/// A set of drawn letters
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draw {
+    /// The letters drawn in alphabetical order
+    pub letters: String,
+    /// Probability of drawing the letters
+    pub probability: f64,
+}
+
+/// Returns the probability that drawing `vowels` vowels and `consonants` consonants from the piles
+/// gives a letter set containing the letters of a word (upper case A-Z)
+pub fn draw_probability(
+    word: &str,
+    vowels: usize,
+    consonants: usize,
+    piles: &PileFrequencies,
+) -> f64 {
+    let word = LetterCounts::from_letters(word);
+
+    pile_probability(&word.vowels(), vowels, &piles.vowels)
+        * pile_probability(&word.consonants(), consonants, &piles.consonants)
+}
+
+/// Returns the most likely letter sets containing the letters of a word (upper case A-Z) when
+/// drawing `vowels` vowels and `consonants` consonants from the piles, most likely first
+pub fn likely_draws(
+    word: &str,
+    vowels: usize,
+    consonants: usize,
+    piles: &PileFrequencies,
+    count: usize,
+) -> Vec<Draw> {
+    let word = LetterCounts::from_letters(word);
+
+    let vowel_draws = pile_draws(&word.vowels(), vowels, &piles.vowels, count);
+    let consonant_draws = pile_draws(&word.consonants(), consonants, &piles.consonants, count);
+
+    // The best combined draws are made from the best draws from each pile
+    let mut draws = Vec::with_capacity(vowel_draws.len() * consonant_draws.len());
+
+    for (v, v_prob) in &vowel_draws {
+        for (c, c_prob) in &consonant_draws {
+            let mut letters = *v;
+
+            for (letter, n) in c.iter() {
+                for _ in 0..n {
+                    letters.add(letter as u8 - b'A');
+                }
+            }
+
+            draws.push(Draw {
+                letters: letters.to_string(),
+                probability: v_prob * c_prob,
+            });
+        }
+    }
+
+    sort_draws(&mut draws);
+    draws.truncate(count);
+
+    draws
+}
+
+/// Word and the probability of drawing a letter set containing it
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordProbability {
+    /// The word
+    pub word: String,
+    /// Probability of a letter set containing the word
+    pub probability: f64,
+}
+
+/// Ranks words by the probability of drawing a letter set containing them, most likely first.
+/// Words of equal probability are in alphabetical order
+pub fn rank_by_draw_probability(
+    words: Vec<String>,
+    vowels: usize,
+    consonants: usize,
+    piles: &PileFrequencies,
+) -> Vec<WordProbability> {
+    let mut ranked = words
+        .into_iter()
+        .map(|word| WordProbability {
+            probability: draw_probability(&word, vowels, consonants, piles),
+            word,
+        })
+        .collect::<Vec<_>>();
+
+    ranked.sort_by(|a, b| {
+        b.probability
+            .partial_cmp(&a.probability)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.word.cmp(&b.word))
+    });
+
+    ranked
+}
+
+/// Returns the probability of drawing `draws` tiles from a pile including all of the needed letters
+fn pile_probability(needed: &LetterCounts, draws: usize, pile: &LetterFrequencies) -> f64 {
+    let total = pile.total() as usize;
+
+    if draws > total || needed.len() > draws {
+        return 0.0;
+    }
+
+    // Number of ways of drawing t tiles from the letters processed so far which include
+    // the needed letters
+    let mut ways = vec![0.0; draws + 1];
+    ways[0] = 1.0;
+
+    for letter in 0..26u8 {
+        let tiles = pile.counts[letter as usize] as usize;
+        let need = needed.get(letter) as usize;
+
+        if need > tiles {
+            return 0.0;
+        }
+
+        if tiles == 0 {
+            continue;
+        }
+
+        let mut next = vec![0.0; draws + 1];
+
+        for (t, w) in ways.iter().enumerate().filter(|(_, w)| **w > 0.0) {
+            for k in need..=tiles.min(draws - t) {
+                next[t + k] += w * binomial(tiles, k);
+            }
+        }
+
+        ways = next;
+    }
+
+    ways[draws] / binomial(total, draws)
+}
+
+/// Returns the most likely complete draws of `draws` tiles from a pile including all of the
+/// needed letters, with their probabilities
+fn pile_draws(
+    needed: &LetterCounts,
+    draws: usize,
+    pile: &LetterFrequencies,
+    count: usize,
+) -> Vec<(LetterCounts, f64)> {
+    let total = pile.total() as usize;
+
+    if draws > total || needed.len() > draws {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    let mut letters = *needed;
+
+    pile_draws_rec(
+        needed,
+        pile,
+        0,
+        draws - needed.len(),
+        &mut letters,
+        1.0,
+        &mut result,
+    );
+
+    // Convert ways to probabilities
+    let all_ways = binomial(total, draws);
+
+    let mut result = result
+        .into_iter()
+        .map(|(letters, ways)| (letters, ways / all_ways))
+        .collect::<Vec<_>>();
+
+    result.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+    result.truncate(count);
+
+    result
+}
+
+fn pile_draws_rec(
+    needed: &LetterCounts,
+    pile: &LetterFrequencies,
+    letter: u8,
+    free: usize,
+    letters: &mut LetterCounts,
+    ways: f64,
+    result: &mut Vec<(LetterCounts, f64)>,
+) {
+    if letter == 26 {
+        if free == 0 {
+            result.push((*letters, ways));
+        }
+
+        return;
+    }
+
+    let tiles = pile.counts[letter as usize] as usize;
+    let need = needed.get(letter) as usize;
+
+    if need > tiles {
+        return;
+    }
+
+    // Add up to `free` extra tiles of this letter
+    for extra in 0..=free.min(tiles - need) {
+        let ways = ways * binomial(tiles, need + extra);
+
+        pile_draws_rec(
+            needed,
+            pile,
+            letter + 1,
+            free - extra,
+            letters,
+            ways,
+            result,
+        );
+
+        letters.add(letter);
+    }
+
+    for _ in 0..=free.min(tiles - need) {
+        letters.remove(letter);
+    }
+}
+
+fn sort_draws(draws: &mut [Draw]) {
+    draws.sort_by(|a, b| {
+        b.probability
+            .partial_cmp(&a.probability)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.letters.cmp(&b.letters))
+    });
+}
+
+/// Returns the number of ways of choosing k items from n
+fn binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+
+    let k = k.min(n - k);
+
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-12
+    }
+
+    #[test]
+    fn probability() {
+        let piles = PileFrequencies::countdown();
+
+        assert_eq!(piles.vowels.total(), 67);
+        assert_eq!(piles.consonants.total(), 74);
+
+        // One vowel drawn
+        assert!(close(draw_probability("A", 1, 0, &piles), 15.0 / 67.0));
+
+        // Two Us from two draws
+        assert!(close(
+            draw_probability("UU", 2, 0, &piles),
+            (5.0 * 4.0) / (67.0 * 66.0)
+        ));
+
+        // Impossible draws
+        assert_eq!(draw_probability("QQ", 3, 6, &piles), 0.0);
+        assert_eq!(draw_probability("AEIOUA", 5, 4, &piles), 0.0);
+
+        // Every draw contains no letters
+        assert!(close(draw_probability("", 4, 5, &piles), 1.0));
+
+        // Probability decreases as the word gets longer
+        let p1 = draw_probability("RATE", 4, 5, &piles);
+        let p2 = draw_probability("RATES", 4, 5, &piles);
+
+        assert!(p1 > p2 && p2 > 0.0);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "A15 E21, I13 o13 U5".parse(),
+            Ok(PileFrequencies::countdown().vowels)
+        );
+        assert_eq!(
+            "A15 E".parse::<LetterFrequencies>(),
+            Err(FrequencyError { token: "E".into() })
+        );
+        assert_eq!(
+            "5A".parse::<LetterFrequencies>(),
+            Err(FrequencyError { token: "5A".into() })
+        );
+    }
+
+    #[test]
+    fn draws() {
+        let piles = PileFrequencies::countdown();
+
+        // All draws of one vowel
+        let draws = likely_draws("", 1, 0, &piles, 10);
+
+        assert_eq!(
+            draws.iter().map(|d| d.letters.as_str()).collect::<Vec<_>>(),
+            vec!["E", "A", "I", "O", "U"]
+        );
+        assert!(close(draws.iter().map(|d| d.probability).sum(), 1.0));
+
+        // Draws containing a word
+        let draws = likely_draws("STARE", 3, 4, &piles, 3);
+
+        assert_eq!(draws.len(), 3);
+        assert!(draws.iter().all(|d| d.letters.len() == 7));
+        assert!(draws[0].probability >= draws[1].probability);
+        assert!(draws[0].letters.contains('E'));
+
+        // Sum of all draws matches the total probability
+        let all = likely_draws("STARE", 3, 4, &piles, usize::MAX);
+
+        assert!(close(
+            all.iter().map(|d| d.probability).sum(),
+            draw_probability("STARE", 3, 4, &piles)
+        ));
+    }
+
+    #[test]
+    fn ranking() {
+        let piles = PileFrequencies::countdown();
+
+        let ranked = rank_by_draw_probability(
+            vec!["JAZZ".into(), "RATE".into(), "QUIZ".into()],
+            4,
+            5,
+            &piles,
+        );
+
+        assert_eq!(ranked[0].word, "RATE");
+        assert_eq!(ranked[1].word, "QUIZ");
+        assert_eq!(ranked[2].probability, 0.0);
+    }
+}
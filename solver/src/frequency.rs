@@ -0,0 +1,151 @@
+//! Word usage frequencies
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::scorer::ScrabbleScorer;
+
+/// Word usage counts, used to judge how common or obscure words are
+#[derive(Debug, Clone, Default)]
+pub struct WordFrequencies {
+    counts: HashMap<String, u64>,
+    max: u64,
+}
+
+impl WordFrequencies {
+    /// Creates an empty set of word frequencies
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the usage count for a word
+    pub fn insert(&mut self, word: &str, count: u64) {
+        self.max = self.max.max(count);
+        self.counts.insert(word.to_ascii_uppercase(), count);
+    }
+
+    /// Returns the usage count for a word (upper case)
+    pub fn get(&self, word: &str) -> Option<u64> {
+        self.counts.get(word).copied()
+    }
+
+    /// Returns the number of words
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns true if there are no words
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns how common a word (upper case) is from 0.0 (unknown) to 1.0 (the most common
+    /// word). Counts are compared on a log scale
+    pub fn commonness(&self, word: &str) -> f64 {
+        match self.get(word) {
+            Some(count) if self.max > 0 => (count as f64).ln_1p() / (self.max as f64).ln_1p(),
+            _ => 0.0,
+        }
+    }
+}
+
+impl FromStr for WordFrequencies {
+    type Err = WordFrequencyError;
+
+    /// Parses lines of a word followed by its usage count, eg. "HOUSE 51234". Blank lines and
+    /// lines starting with '#' are ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut frequencies = Self::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split(|c: char| c.is_whitespace() || c == ',');
+
+            let word = fields.next().unwrap_or_default();
+            let count = fields.find(|f| !f.is_empty()).and_then(|f| f.parse().ok());
+
+            match count {
+                Some(count) if word.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    frequencies.insert(word, count)
+                }
+                _ => Err(WordFrequencyError { line: i + 1 })?,
+            }
+        }
+
+        Ok(frequencies)
+    }
+}
+
+/// Word frequency parse error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordFrequencyError {
+    /// Line number of the invalid line (1 based)
+    pub line: usize,
+}
+
+impl fmt::Display for WordFrequencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid word frequency on line {}, expected a word and a count",
+            self.line
+        )
+    }
+}
+
+impl Error for WordFrequencyError {}
+
+/// Returns how obscure a word (upper case) is from 0.0 to 1.0. With word frequencies this is how
+/// uncommon the word is. Without them the rarity of the word's letters is used instead
+pub fn obscurity(word: &str, frequencies: Option<&WordFrequencies>) -> f64 {
+    match frequencies {
+        Some(frequencies) => 1.0 - frequencies.commonness(word),
+        None => {
+            if word.is_empty() {
+                return 0.0;
+            }
+
+            // Scale Scrabble tile values of 1 to 10 to 0.0 to 1.0
+            let total = word
+                .chars()
+                .map(|c| ScrabbleScorer::letter_value(c) - 1)
+                .sum::<u32>();
+
+            total as f64 / (word.len() as f64 * 9.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frequencies() {
+        let frequencies: WordFrequencies =
+            "# comment\nthe 1000\n\nrust,10\nzax 0\n".parse().unwrap();
+
+        assert_eq!(frequencies.len(), 3);
+        assert_eq!(frequencies.get("RUST"), Some(10));
+        assert_eq!(frequencies.commonness("THE"), 1.0);
+        assert_eq!(frequencies.commonness("ZAX"), 0.0);
+        assert_eq!(frequencies.commonness("UNKNOWN"), 0.0);
+        assert!(frequencies.commonness("RUST") > 0.3 && frequencies.commonness("RUST") < 0.4);
+
+        assert_eq!(
+            "the 10\nrust".parse::<WordFrequencies>().unwrap_err(),
+            WordFrequencyError { line: 2 }
+        );
+
+        assert_eq!(obscurity("THE", Some(&frequencies)), 0.0);
+        assert_eq!(obscurity("EAT", None), 0.0);
+        assert_eq!(obscurity("ZZ", None), 1.0);
+    }
+}
@@ -0,0 +1,174 @@
+//! Anagram signature index solver backend
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use dictionary::Dictionary;
+
+use crate::backend::SolverBackend;
+use crate::counts::LetterCounts;
+use crate::limits::Budget;
+use crate::{SearchOutcome, SearchStats, SolverArgs};
+
+/// Index of dictionary words by their sorted letters (signature). Each search looks up every
+/// sub-multiset of the letters (at most 2^9 for nine different letters) instead of walking the
+/// dictionary tree. Searches are single threaded and can't be traced. Searches reusing letters
+/// scan every signature in the index
+pub struct SignatureIndex {
+    words: HashMap<LetterCounts, Vec<String>>,
+}
+
+impl SignatureIndex {
+    /// Builds the index from the words in a dictionary. The index should be used with the
+    /// same dictionary
+    pub fn new(dictionary: &Dictionary) -> Self {
+        let mut words: HashMap<LetterCounts, Vec<String>> = HashMap::new();
+
+        for word in dictionary.words() {
+            words
+                .entry(LetterCounts::from_letters(&word))
+                .or_default()
+                .push(word);
+        }
+
+        Self { words }
+    }
+
+    /// Returns the number of signatures in the index
+    pub fn signature_count(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns the words with the same letters as the given letters (upper case A-Z) in
+    /// alphabetical order
+    pub fn anagrams(&self, letters: &str) -> &[String] {
+        self.words
+            .get(&LetterCounts::from_letters(letters))
+            .map_or(&[], |w| w.as_slice())
+    }
+}
+
+impl SolverBackend for SignatureIndex {
+    fn name(&self) -> &'static str {
+        "index"
+    }
+
+    fn search(&self, args: &SolverArgs) -> SearchOutcome {
+        let start_time = Instant::now();
+
+        let budget = Budget::new(&args.limits);
+
+        let letters = LetterCounts::from_letters(&args.letters);
+
+        let mut words = Vec::new();
+
+        if args.reuse_letters {
+            // Any word made only from the letters
+            for (signature, sig_words) in &self.words {
+                if !budget.visit() {
+                    break;
+                }
+
+                if signature
+                    .iter()
+                    .all(|(c, _)| letters.get(c as u8 - b'A') > 0)
+                {
+                    words.extend(sig_words.iter().cloned());
+                }
+            }
+        } else {
+            // Look up each sub-multiset of the letters
+            let distinct = letters.iter().collect::<Vec<_>>();
+
+            self.sub_multisets(&distinct, &mut LetterCounts::new(), &budget, &mut words);
+        }
+
+        words.sort();
+
+        let elapsed = start_time.elapsed();
+
+        SearchOutcome {
+            words,
+            truncated: budget.stopped(),
+            stats: SearchStats {
+                threads: 1,
+                nodes: budget.nodes(),
+                work_time: elapsed,
+                elapsed,
+            },
+        }
+    }
+}
+
+impl SignatureIndex {
+    fn sub_multisets(
+        &self,
+        distinct: &[(char, u8)],
+        signature: &mut LetterCounts,
+        budget: &Budget,
+        words: &mut Vec<String>,
+    ) {
+        let Some(((c, count), rest)) = distinct.split_first() else {
+            // Look up the signature
+            if !signature.is_empty() && budget.visit() {
+                if let Some(sig_words) = self.words.get(signature) {
+                    words.extend(sig_words.iter().cloned());
+                }
+            }
+
+            return;
+        };
+
+        let letter = *c as u8 - b'A';
+
+        for n in 0..=*count {
+            if budget.stopped() {
+                break;
+            }
+
+            self.sub_multisets(rest, signature, budget, words);
+
+            if n < *count {
+                signature.add(letter);
+            }
+        }
+
+        for _ in 0..*count {
+            signature.remove(letter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_words;
+
+    const DICT: &str = "a\nrust\nrusty\nrut\nruts\ntrust\ntsar\nstar\nrats\nstare\nrest\nturtus";
+
+    #[test]
+    fn identical_results() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let index = SignatureIndex::new(&dictionary);
+
+        assert_eq!(index.anagrams("ARTS"), &["RATS", "STAR", "TSAR"]);
+
+        for letters in ["RUTS", "TRUSTY", "STAREAUT", "XYZ", "TTTRUSU"] {
+            for reuse_letters in [false, true] {
+                let trie = find_words(SolverArgs {
+                    reuse_letters,
+                    ..SolverArgs::new(letters, &dictionary)
+                });
+
+                let indexed = find_words(SolverArgs {
+                    reuse_letters,
+                    backend: &index,
+                    ..SolverArgs::new(letters, &dictionary)
+                });
+
+                assert_eq!(trie, indexed, "{letters} reuse {reuse_letters}");
+            }
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Letter validation and normalisation
+
+use std::error::Error;
+use std::fmt;
+
+use crate::counts::MAX_LETTER_COUNT;
+
+/// Rules for validating a set of letters
+#[derive(Debug, Clone)]
+pub struct LetterRules {
+    /// Minimum number of letters
+    pub min_letters: usize,
+    /// Maximum number of letters
+    pub max_letters: Option<usize>,
+    /// Minimum number of vowels
+    pub min_vowels: usize,
+    /// Minimum number of consonants
+    pub min_consonants: usize,
+}
+
+impl LetterRules {
+    /// Countdown rules: nine letters with at least three vowels and four consonants
+    pub fn countdown() -> Self {
+        Self {
+            min_letters: 9,
+            max_letters: Some(9),
+            min_vowels: 3,
+            min_consonants: 4,
+        }
+    }
+}
+
+impl Default for LetterRules {
+    fn default() -> Self {
+        Self {
+            min_letters: 1,
+            max_letters: None,
+            min_vowels: 0,
+            min_consonants: 0,
+        }
+    }
+}
+
+/// Letter validation errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LetterError {
+    /// A character which is not a letter was found at the position (1 based)
+    InvalidCharacter {
+        /// Position of the character in the input (1 based)
+        position: usize,
+        /// The invalid character
+        character: char,
+    },
+    /// Too few letters
+    TooFewLetters {
+        /// Number of letters given
+        count: usize,
+        /// Minimum number of letters
+        min: usize,
+    },
+    /// Too many letters
+    TooManyLetters {
+        /// Number of letters given
+        count: usize,
+        /// Maximum number of letters
+        max: usize,
+    },
+    /// A letter is repeated more times than can be counted
+    TooManyRepeats {
+        /// The repeated letter
+        letter: char,
+        /// Number of times the letter was given
+        count: usize,
+        /// Maximum number of times a letter can be given
+        max: usize,
+    },
+    /// Too few vowels
+    TooFewVowels {
+        /// Number of vowels given
+        count: usize,
+        /// Minimum number of vowels
+        min: usize,
+    },
+    /// Too few consonants
+    TooFewConsonants {
+        /// Number of consonants given
+        count: usize,
+        /// Minimum number of consonants
+        min: usize,
+    },
+}
+
+impl fmt::Display for LetterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LetterError::InvalidCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "Invalid character '{character}' at position {position}, letters must be A-Z only"
+            ),
+            LetterError::TooFewLetters { count, min } => {
+                write!(f, "At least {min} letters must be provided ({count} given)")
+            }
+            LetterError::TooManyLetters { count, max } => {
+                write!(f, "At most {max} letters can be provided ({count} given)")
+            }
+            LetterError::TooManyRepeats { letter, count, max } => {
+                write!(
+                    f,
+                    "{letter} can be provided at most {max} times ({count} given)"
+                )
+            }
+            LetterError::TooFewVowels { count, min } => {
+                write!(f, "At least {min} vowels must be provided ({count} given)")
+            }
+            LetterError::TooFewConsonants { count, min } => {
+                write!(
+                    f,
+                    "At least {min} consonants must be provided ({count} given)"
+                )
+            }
+        }
+    }
+}
+
+impl Error for LetterError {}
+
+/// Returns true if the letter (upper case A-Z) is a vowel
+#[inline]
+pub fn is_vowel(letter: char) -> bool {
+    matches!(letter, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+/// Validates and normalises a string of letters. Letters are converted to upper case and
+/// white space is removed
+pub fn normalise_letters(input: &str, rules: &LetterRules) -> Result<String, LetterError> {
+    let mut letters = String::with_capacity(input.len());
+
+    for (i, c) in input.chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        if !c.is_ascii_alphabetic() {
+            Err(LetterError::InvalidCharacter {
+                position: i + 1,
+                character: c,
+            })?;
+        }
+
+        letters.push(c.to_ascii_uppercase());
+    }
+
+    let count = letters.len();
+
+    if count < rules.min_letters {
+        Err(LetterError::TooFewLetters {
+            count,
+            min: rules.min_letters,
+        })?;
+    }
+
+    if let Some(max) = rules.max_letters {
+        if count > max {
+            Err(LetterError::TooManyLetters { count, max })?;
+        }
+    }
+
+    let mut repeats = [0; 26];
+
+    for c in letters.bytes() {
+        repeats[(c - b'A') as usize] += 1;
+    }
+
+    if let Some((i, &count)) = repeats
+        .iter()
+        .enumerate()
+        .find(|(_, &count)| count > MAX_LETTER_COUNT)
+    {
+        Err(LetterError::TooManyRepeats {
+            letter: (b'A' + i as u8) as char,
+            count,
+            max: MAX_LETTER_COUNT,
+        })?;
+    }
+
+    let vowels = letters.chars().filter(|c| is_vowel(*c)).count();
+
+    if vowels < rules.min_vowels {
+        Err(LetterError::TooFewVowels {
+            count: vowels,
+            min: rules.min_vowels,
+        })?;
+    }
+
+    if count - vowels < rules.min_consonants {
+        Err(LetterError::TooFewConsonants {
+            count: count - vowels,
+            min: rules.min_consonants,
+        })?;
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalise() {
+        let rules = LetterRules::default();
+
+        assert_eq!(normalise_letters("rUst", &rules), Ok("RUST".into()));
+        assert_eq!(
+            normalise_letters("moan horse", &rules),
+            Ok("MOANHORSE".into())
+        );
+
+        assert_eq!(
+            normalise_letters("ru5t", &rules),
+            Err(LetterError::InvalidCharacter {
+                position: 3,
+                character: '5'
+            })
+        );
+
+        assert_eq!(
+            normalise_letters("", &rules),
+            Err(LetterError::TooFewLetters { count: 0, min: 1 })
+        );
+
+        assert_eq!(
+            normalise_letters(&"ab".repeat(256), &rules),
+            Err(LetterError::TooManyRepeats {
+                letter: 'A',
+                count: 256,
+                max: 255
+            })
+        );
+        assert!(normalise_letters(&"ab".repeat(255), &rules).is_ok());
+    }
+
+    #[test]
+    fn countdown() {
+        let rules = LetterRules::countdown();
+
+        assert!(normalise_letters("anedrwips", &rules).is_ok());
+
+        assert_eq!(
+            normalise_letters("anedrwip", &rules),
+            Err(LetterError::TooFewLetters { count: 8, min: 9 })
+        );
+        assert_eq!(
+            normalise_letters("anedrwipsx", &rules),
+            Err(LetterError::TooManyLetters { count: 10, max: 9 })
+        );
+        assert_eq!(
+            normalise_letters("anxdrwtps", &rules),
+            Err(LetterError::TooFewVowels { count: 1, min: 3 })
+        );
+        assert_eq!(
+            normalise_letters("aeiouaeis", &rules),
+            Err(LetterError::TooFewConsonants { count: 1, min: 4 })
+        );
+    }
+}
@@ -2,38 +2,214 @@
 
 //! Countdown letters game solver
 
+mod backend;
+mod batch;
+mod bot;
+mod check;
+mod conundrum;
+mod counts;
+mod difficulty;
+mod draw;
+mod frequency;
+mod index;
+mod letters;
+mod limits;
+mod near;
+mod parallel;
+mod phrase;
+mod pile;
+mod result;
+mod rng;
+mod scorer;
+mod simulate;
+mod top;
+mod trace;
+
+use std::borrow::Cow;
 use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
 use dictionary::{Dictionary, LetterNext};
 
+pub use crate::backend::{SolverBackend, TrieBackend};
+pub use crate::batch::{solve_batch, BatchArgs, BatchOutcome, BatchResult};
+pub use crate::bot::{Bot, Skill};
+pub use crate::check::{check_word, find_word_errors, WordError};
+pub use crate::conundrum::{rate_difficulty, Conundrum, ConundrumGenerator, CONUNDRUM_LEN};
+pub use crate::counts::LetterCounts;
+pub use crate::difficulty::{rate_letters, Difficulty};
+pub use crate::draw::{
+    draw_probability, likely_draws, rank_by_draw_probability, Draw, FrequencyError,
+    LetterFrequencies, PileFrequencies, WordProbability,
+};
+pub use crate::frequency::{obscurity, WordFrequencies, WordFrequencyError};
+pub use crate::index::SignatureIndex;
+pub use crate::letters::{is_vowel, normalise_letters, LetterError, LetterRules};
+use crate::limits::Budget;
+pub use crate::limits::{CancelToken, SearchLimits};
+pub use crate::near::{find_near_misses, NearMiss, NearMisses};
+pub use crate::phrase::{find_phrases, PhraseArgs};
+pub use crate::pile::{parse_choices, DrawError, LetterPile, LetterPiles, PileKind};
+pub use crate::result::{find_word_results, WordResult};
+pub use crate::rng::{time_seed, Rng};
+pub use crate::scorer::{
+    score_words, CountdownScorer, LengthScorer, LengthSquaredScorer, ScoredWord, Scorer,
+    ScrabbleScorer,
+};
+pub use crate::simulate::{simulate_split, SimulationArgs, SplitStats};
+pub use crate::top::{find_top_scored_words, find_top_words, search_top_words, TopOutcome};
+use crate::trace::trace_lookup;
+pub use crate::trace::{
+    JsonTraceSink, RecordingTraceSink, TextTraceSink, TraceEvent, TraceKind, TraceSink,
+};
+
 /// Arguments for the countdown letters solver
 pub struct SolverArgs<'a> {
     /// String of letters to use (must be upper case A-Z)
-    pub letters: &'a str,
+    pub letters: Cow<'a, str>,
     /// Dictionary to use
     pub dictionary: &'a Dictionary,
     /// Letters can be reused flag
     pub reuse_letters: bool,
-    /// Debug output
-    pub debug: bool,
+    /// Receives search trace events
+    pub trace: Option<&'a dyn TraceSink>,
+    /// Number of threads to search with (0 to use all available cores).
+    /// Traced searches are always single threaded
+    pub threads: usize,
+    /// Limits on the amount of searching done
+    pub limits: SearchLimits<'a>,
+    /// Search implementation to use
+    pub backend: &'a dyn SolverBackend,
+}
+
+impl<'a> SolverArgs<'a> {
+    /// Creates solver arguments with default options. The letters must be upper case A-Z.
+    /// Use `SolverArgs::validated` for unchecked input
+    pub fn new(letters: &'a str, dictionary: &'a Dictionary) -> Self {
+        Self {
+            letters: Cow::Borrowed(letters),
+            dictionary,
+            reuse_letters: false,
+            trace: None,
+            threads: 1,
+            limits: SearchLimits::default(),
+            backend: &TrieBackend,
+        }
+    }
+
+    /// Creates solver arguments with default options from unchecked input. The letters are
+    /// validated and normalised with `normalise_letters`
+    pub fn validated(
+        letters: &str,
+        dictionary: &'a Dictionary,
+        rules: &LetterRules,
+    ) -> Result<Self, LetterError> {
+        let letters = normalise_letters(letters, rules)?;
+
+        Ok(Self {
+            letters: Cow::Owned(letters),
+            ..Self::new("", dictionary)
+        })
+    }
+
+    /// Returns the number of threads to search with
+    fn thread_count(&self) -> usize {
+        if self.trace.is_some() {
+            1
+        } else if self.threads == 0 {
+            std::thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            self.threads
+        }
+    }
+}
+
+/// Word search results
+pub struct SearchOutcome {
+    /// Words found in alphabetical order
+    pub words: Vec<String>,
+    /// The search was stopped by one of the search limits before completing
+    pub truncated: bool,
+    /// Search statistics
+    pub stats: SearchStats,
+}
+
+/// Word search statistics
+#[derive(Debug, Clone)]
+pub struct SearchStats {
+    /// Number of threads which searched
+    pub threads: usize,
+    /// Number of dictionary tree nodes visited
+    pub nodes: u64,
+    /// Total time spent searching by all threads
+    pub work_time: Duration,
+    /// Elapsed time
+    pub elapsed: Duration,
+}
+
+impl SearchStats {
+    /// Returns the estimated speed-up achieved by searching with multiple threads
+    /// (total search time of all threads divided by the elapsed time)
+    pub fn speed_up(&self) -> f64 {
+        if self.elapsed.is_zero() {
+            1.0
+        } else {
+            self.work_time.as_secs_f64() / self.elapsed.as_secs_f64()
+        }
+    }
 }
 
 /// Find words in the provides dictionary using the provided letters
 pub fn find_words(args: SolverArgs) -> Vec<String> {
-    let mut result = HashSet::new();
+    search_words(args).words
+}
 
-    // Dictionary entry element numbers for each letter
-    let mut letter_elems = args
-        .letters
-        .chars()
-        .map(|c| c as u8 - b'A')
-        .collect::<Vec<u8>>();
+/// Find words in the provided dictionary using the provided letters, returning search statistics
+pub fn search_words(args: SolverArgs) -> SearchOutcome {
+    args.backend.search(&args)
+}
 
-    if args.reuse_letters {
-        // Remove duplicate entries if allowed to reuse letters
-        letter_elems.sort();
-        letter_elems.dedup();
+/// Find words by walking the dictionary tree
+pub(crate) fn trie_search(args: &SolverArgs) -> SearchOutcome {
+    let start_time = Instant::now();
+
+    // Dictionary entry element numbers for each letter
+    let letter_elems = letter_elems(args);
+
+    let threads = args.thread_count();
+
+    // Search budget
+    let budget = Budget::new(&args.limits);
+
+    let (result, work_time, threads) = if threads > 1 {
+        parallel::find_words_parallel(args, &letter_elems, threads, &budget)
+    } else {
+        (
+            find_words_single(args, &letter_elems, &budget),
+            start_time.elapsed(),
+            1,
+        )
+    };
+
+    // Convert hash set to sorted vector
+    let mut words = result.into_iter().collect::<Vec<_>>();
+    words.sort();
+
+    SearchOutcome {
+        words,
+        truncated: budget.stopped(),
+        stats: SearchStats {
+            threads,
+            nodes: budget.nodes(),
+            work_time,
+            elapsed: start_time.elapsed(),
+        },
     }
+}
+
+/// Find words using a single thread
+fn find_words_single(args: &SolverArgs, letter_elems: &[u8], budget: &Budget) -> HashSet<String> {
+    let mut result = HashSet::new();
 
     // Vector of chosen letter elements
     let mut chosen = Vec::with_capacity(letter_elems.len());
@@ -43,16 +219,33 @@ pub fn find_words(args: SolverArgs) -> Vec<String> {
 
     // Start search recursion
     find_words_rec(
-        &args,
+        args,
         &mut chosen,
         &mut is_chosen,
-        &letter_elems,
+        letter_elems,
         0,
+        budget,
         &mut result,
     );
 
-    // Convert hash set to vector
-    result.into_iter().collect()
+    result
+}
+
+/// Converts the letters to dictionary entry element numbers
+fn letter_elems(args: &SolverArgs) -> Vec<u8> {
+    let mut letter_elems = args
+        .letters
+        .chars()
+        .map(|c| c as u8 - b'A')
+        .collect::<Vec<u8>>();
+
+    if args.reuse_letters {
+        // Remove duplicate entries if allowed to reuse letters
+        letter_elems.sort();
+        letter_elems.dedup();
+    }
+
+    letter_elems
 }
 
 fn find_words_rec(
@@ -61,8 +254,13 @@ fn find_words_rec(
     is_chosen: &mut Vec<bool>,
     letter_elems: &[u8],
     dict_elem: usize,
+    budget: &Budget,
     result: &mut HashSet<String>,
 ) {
+    if budget.stopped() {
+        return;
+    }
+
     for i in 0..letter_elems.len() {
         if is_chosen[i] && !args.reuse_letters {
             // This letter has already been chosen
@@ -80,8 +278,14 @@ fn find_words_rec(
             .dictionary
             .lookup_elem_letter_num(dict_elem, chosen_letter);
 
-        if args.debug {
-            debug_lookup(chosen, &dict_elem);
+        if !budget.visit() {
+            // Search limit reached
+            chosen.pop();
+            return;
+        }
+
+        if let Some(sink) = args.trace {
+            trace_lookup(sink, chosen, dict_elem, true);
         }
 
         // End of a word?
@@ -97,7 +301,15 @@ fn find_words_rec(
             LetterNext::Next(e) | LetterNext::EndNext(e) => {
                 is_chosen[i] = true;
 
-                find_words_rec(args, chosen, is_chosen, letter_elems, e as usize, result);
+                find_words_rec(
+                    args,
+                    chosen,
+                    is_chosen,
+                    letter_elems,
+                    e as usize,
+                    budget,
+                    result,
+                );
 
                 is_chosen[i] = false;
             }
@@ -119,14 +331,6 @@ fn chosen_string(chosen: &[u8]) -> String {
         .collect::<String>()
 }
 
-#[cold]
-fn debug_lookup(chosen: &[u8], dict_elem: &LetterNext) {
-    let string = chosen_string(chosen);
-    let indent = string.len() - 1;
-
-    println!("{:indent$}{} ({:?})", "", string, dict_elem);
-}
-
 #[cfg(test)]
 mod tests {
     use dictionary::{Dictionary, LetterNext};
@@ -144,15 +348,47 @@ mod tests {
         let dictionary = Dictionary::new_from_string("rust", Default::default(), false).unwrap();
 
         // Find words
+        let trace = RecordingTraceSink::new();
+
         let words = find_words(SolverArgs {
-            letters: "TRUS",
-            dictionary: &dictionary,
-            reuse_letters: false,
-            debug: true,
+            trace: Some(&trace),
+            ..SolverArgs::new("TRUS", &dictionary)
         });
 
         // Should be one found
         assert_eq!(words, vec!["RUST"]);
+
+        // Check the trace
+        let events = trace
+            .into_events()
+            .into_iter()
+            .filter(|e| e.kind != TraceKind::DeadEnd)
+            .map(|e| (e.kind, e.path))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            events,
+            vec![
+                (TraceKind::Descend, "R".to_string()),
+                (TraceKind::Descend, "RU".to_string()),
+                (TraceKind::Descend, "RUS".to_string()),
+                (TraceKind::Word, "RUST".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn validated() {
+        let dictionary = Dictionary::new_from_string("rust", Default::default(), false).unwrap();
+
+        let args = SolverArgs::validated("t r u s", &dictionary, &Default::default()).unwrap();
+
+        assert_eq!(find_words(args), vec!["RUST"]);
+
+        assert!(matches!(
+            SolverArgs::validated("tru$", &dictionary, &Default::default()),
+            Err(LetterError::InvalidCharacter { position: 4, .. })
+        ));
     }
 
     #[test]
@@ -169,15 +405,18 @@ mod tests {
         let dictionary = Dictionary::new_from_string(dict, Default::default(), false).unwrap();
 
         // Find words
-        let mut words = find_words(SolverArgs {
-            letters: "TRUS",
-            dictionary: &dictionary,
-            reuse_letters: false,
-            debug: true,
-        });
+        let trace = TextTraceSink::new(Vec::new());
 
-        words.sort();
+        let words = find_words(SolverArgs {
+            trace: Some(&trace),
+            ..SolverArgs::new("TRUS", &dictionary)
+        });
 
         assert_eq!(words, vec!["RUST", "RUT", "RUTS"]);
+
+        // Check the trace for the word "RUTS"
+        let trace = String::from_utf8(trace.into_inner()).unwrap();
+
+        assert!(trace.contains("\n  RUT (EndNext(5))\n   RUTS (End)\n"));
     }
 }
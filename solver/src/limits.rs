@@ -0,0 +1,177 @@
+//! Search budgets, deadlines and cancellation
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
+
+/// Limits on the amount of searching done. When a limit is hit the search stops and
+/// the words found so far are returned
+#[derive(Default, Clone)]
+pub struct SearchLimits<'a> {
+    /// Time at which to stop searching
+    pub deadline: Option<Instant>,
+    /// Maximum number of dictionary tree nodes to visit
+    pub max_nodes: Option<u64>,
+    /// Token which stops the search when cancelled
+    pub cancel: Option<&'a CancelToken>,
+}
+
+/// Search cancellation token. May be cancelled from another thread
+#[derive(Default, Debug)]
+pub struct CancelToken(AtomicBool);
+
+impl CancelToken {
+    /// Creates a new token
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels any searches using this token
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Number of node visits between deadline and cancellation checks
+const CHECK_INTERVAL: u64 = 1024;
+
+/// Search budget shared between search threads
+pub(crate) struct Budget<'a> {
+    limits: &'a SearchLimits<'a>,
+    nodes: AtomicU64,
+    stopped: AtomicBool,
+}
+
+impl<'a> Budget<'a> {
+    pub(crate) fn new(limits: &'a SearchLimits<'a>) -> Self {
+        let budget = Self {
+            limits,
+            nodes: AtomicU64::new(0),
+            stopped: AtomicBool::new(false),
+        };
+
+        // Don't start if already past the deadline or cancelled
+        budget.check();
+
+        budget
+    }
+
+    /// Records a dictionary tree node visit. Returns false if the search should stop
+    #[inline]
+    pub(crate) fn visit(&self) -> bool {
+        let nodes = self.nodes.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if let Some(max_nodes) = self.limits.max_nodes {
+            if nodes > max_nodes {
+                self.stop();
+            }
+        }
+
+        if nodes.is_multiple_of(CHECK_INTERVAL) {
+            self.check();
+        }
+
+        !self.stopped()
+    }
+
+    /// Returns true if the search has been stopped
+    #[inline]
+    pub(crate) fn stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of dictionary tree nodes visited
+    pub(crate) fn nodes(&self) -> u64 {
+        self.nodes.load(Ordering::Relaxed)
+    }
+
+    #[cold]
+    fn check(&self) {
+        if let Some(deadline) = self.limits.deadline {
+            if Instant::now() >= deadline {
+                self.stop();
+            }
+        }
+
+        if let Some(cancel) = self.limits.cancel {
+            if cancel.is_cancelled() {
+                self.stop();
+            }
+        }
+    }
+
+    #[cold]
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use dictionary::Dictionary;
+
+    use super::*;
+    use crate::{search_words, SolverArgs};
+
+    const DICT: &str = "\
+        rut\n\
+        ruts\n\
+        rust\n\
+        rusty\n\
+        star\n\
+        stars\n\
+        tsar\n\
+        tsars\n\
+        ";
+
+    #[test]
+    fn max_nodes() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let full = search_words(SolverArgs::new("TRUSSA", &dictionary));
+
+        assert!(!full.truncated);
+        assert!(full.stats.nodes > 10);
+
+        let limited = search_words(SolverArgs {
+            limits: SearchLimits {
+                max_nodes: Some(10),
+                ..Default::default()
+            },
+            ..SolverArgs::new("TRUSSA", &dictionary)
+        });
+
+        assert!(limited.truncated);
+        assert!(limited.words.len() < full.words.len());
+        assert!(limited.words.iter().all(|w| full.words.contains(w)));
+    }
+
+    #[test]
+    fn cancelled() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let cancel = CancelToken::new();
+        cancel.cancel();
+
+        for threads in [1, 4] {
+            let outcome = search_words(SolverArgs {
+                reuse_letters: true,
+                threads,
+                limits: SearchLimits {
+                    cancel: Some(&cancel),
+                    deadline: Some(Instant::now() + Duration::from_secs(60)),
+                    ..Default::default()
+                },
+                ..SolverArgs::new("TRUSAY", &dictionary)
+            });
+
+            assert!(outcome.truncated);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Near miss words (one extra or one changed letter)
+
+use std::cmp::Ordering;
+
+use dictionary::{Dictionary, LetterNext};
+
+use crate::chosen_string;
+use crate::counts::LetterCounts;
+
+/// A word which can be made with one extra or one changed letter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearMiss {
+    /// The word
+    pub word: String,
+    /// The letter added to the letters
+    pub added: char,
+    /// The letter removed from the letters for a changed letter
+    pub removed: Option<char>,
+}
+
+/// Near miss words
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NearMisses {
+    /// Words which can be made by adding one letter to the letters
+    pub added: Vec<NearMiss>,
+    /// Words using every letter with one letter changed (blanagrams)
+    pub swapped: Vec<NearMiss>,
+}
+
+/// Finds words in the dictionary which can't be made from the letters (upper case A-Z) but can be
+/// made by adding one letter, and words which use every letter with one letter changed.
+/// Letters are not reused. Words are returned longest first then alphabetical
+pub fn find_near_misses(letters: &str, dictionary: &Dictionary) -> NearMisses {
+    let tiles = LetterCounts::from_letters(letters);
+
+    // Walk the dictionary with one wildcard tile
+    let mut found = Vec::new();
+    let mut walk_tiles = tiles;
+
+    find_near_misses_rec(
+        dictionary,
+        0,
+        &mut walk_tiles,
+        None,
+        &mut Vec::with_capacity(letters.len() + 1),
+        &mut found,
+    );
+
+    found.sort_by(|(a, _), (b, _)| match b.len().cmp(&a.len()) {
+        Ordering::Equal => a.cmp(b),
+        ord => ord,
+    });
+
+    let mut near_misses = NearMisses::default();
+
+    for (word, added) in found {
+        let added = (b'A' + added) as char;
+
+        if word.len() == letters.len() {
+            // Work out which tile was swapped out
+            let mut word_counts = LetterCounts::from_letters(&word);
+            word_counts.remove(added as u8 - b'A');
+
+            let removed = word_counts.missing(&tiles).iter().next().map(|(c, _)| c);
+
+            near_misses.swapped.push(NearMiss {
+                word: word.clone(),
+                added,
+                removed,
+            });
+        }
+
+        near_misses.added.push(NearMiss {
+            word,
+            added,
+            removed: None,
+        });
+    }
+
+    near_misses
+}
+
+fn find_near_misses_rec(
+    dictionary: &Dictionary,
+    dict_elem: usize,
+    tiles: &mut LetterCounts,
+    wildcard: Option<u8>,
+    chosen: &mut Vec<u8>,
+    found: &mut Vec<(String, u8)>,
+) {
+    for letter in 0..26u8 {
+        // Use a tile if there is one, otherwise the wildcard. A word can only need the wildcard
+        // for the last of its occurrences of a letter so each word is found once
+        let use_tile = tiles.get(letter) > 0;
+
+        if !use_tile && wildcard.is_some() {
+            continue;
+        }
+
+        let next = dictionary.lookup_elem_letter_num(dict_elem, letter);
+
+        if matches!(next, LetterNext::None) {
+            continue;
+        }
+
+        let next_wildcard = if use_tile {
+            tiles.remove(letter);
+            wildcard
+        } else {
+            Some(letter)
+        };
+
+        chosen.push(letter);
+
+        // End of a word using the wildcard?
+        if let (LetterNext::End | LetterNext::EndNext(_), Some(added)) = (next, next_wildcard) {
+            found.push((chosen_string(chosen), added));
+        }
+
+        // Recurse to next letter
+        if let LetterNext::Next(e) | LetterNext::EndNext(e) = next {
+            find_near_misses_rec(dictionary, e as usize, tiles, next_wildcard, chosen, found);
+        }
+
+        chosen.pop();
+
+        if use_tile {
+            tiles.add(letter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn near_misses() {
+        let dictionary = Dictionary::new_from_string(
+            "rust\nrusty\nrut\nruts\ntrust\ntsar\nstar\nrats\nstare\nrest",
+            Default::default(),
+            false,
+        )
+        .unwrap();
+
+        let near = find_near_misses("RUTS", &dictionary);
+
+        let added = near
+            .added
+            .iter()
+            .map(|n| format!("{}+{}", n.word, n.added))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            added,
+            vec!["RUSTY+Y", "TRUST+T", "RATS+A", "REST+E", "STAR+A", "TSAR+A"]
+        );
+
+        assert_eq!(
+            near.swapped,
+            vec![
+                NearMiss {
+                    word: "RATS".into(),
+                    added: 'A',
+                    removed: Some('U'),
+                },
+                NearMiss {
+                    word: "REST".into(),
+                    added: 'E',
+                    removed: Some('U'),
+                },
+                NearMiss {
+                    word: "STAR".into(),
+                    added: 'A',
+                    removed: Some('U'),
+                },
+                NearMiss {
+                    word: "TSAR".into(),
+                    added: 'A',
+                    removed: Some('U'),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Multi-threaded word search
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use dictionary::LetterNext;
+
+use crate::limits::Budget;
+use crate::{chosen_string, find_words_rec, SolverArgs};
+
+/// Find words using multiple threads. Work is split by the first letter, or the first two
+/// letters if there are too few first letters to keep all of the threads busy.
+/// Returns the words found, the total time spent searching by all threads and the number of
+/// threads started (no more than the number of work units)
+pub(crate) fn find_words_parallel(
+    args: &SolverArgs,
+    letter_elems: &[u8],
+    threads: usize,
+    budget: &Budget,
+) -> (HashSet<String>, Duration, usize) {
+    // Build list of work units
+    let mut units = next_tiles(args, letter_elems, &[])
+        .into_iter()
+        .map(|i| vec![i])
+        .collect::<Vec<_>>();
+
+    if units.len() < threads * 2 {
+        units = units
+            .into_iter()
+            .flat_map(|unit| {
+                let next = next_tiles(args, letter_elems, &unit);
+
+                if next.is_empty() {
+                    vec![unit]
+                } else {
+                    next.into_iter().map(|j| vec![unit[0], j]).collect()
+                }
+            })
+            .collect();
+    }
+
+    let workers = threads.min(units.len());
+
+    // Next work unit to process
+    let next_unit = AtomicUsize::new(0);
+
+    // Merged results
+    let result = Mutex::new((HashSet::new(), Duration::ZERO));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                let start_time = Instant::now();
+                let mut words = HashSet::new();
+
+                loop {
+                    let unit = next_unit.fetch_add(1, Ordering::Relaxed);
+
+                    if unit >= units.len() || budget.stopped() {
+                        break;
+                    }
+
+                    find_words_unit(args, letter_elems, &units[unit], budget, &mut words);
+                }
+
+                let mut result = result.lock().unwrap();
+
+                result.0.extend(words);
+                result.1 += start_time.elapsed();
+            });
+        }
+    });
+
+    let (words, work_time) = result.into_inner().unwrap();
+
+    (words, work_time, workers)
+}
+
+/// Returns the tile indexes which can follow the chosen tiles, one for each distinct letter
+fn next_tiles(args: &SolverArgs, letter_elems: &[u8], chosen: &[usize]) -> Vec<usize> {
+    let mut tried = [false; 26];
+
+    (0..letter_elems.len())
+        .filter(|i| args.reuse_letters || !chosen.contains(i))
+        .filter(|&i| !std::mem::replace(&mut tried[letter_elems[i] as usize], true))
+        .collect()
+}
+
+/// Searches for all words starting with the tiles in the work unit
+fn find_words_unit(
+    args: &SolverArgs,
+    letter_elems: &[u8],
+    unit: &[usize],
+    budget: &Budget,
+    result: &mut HashSet<String>,
+) {
+    let mut chosen = Vec::with_capacity(letter_elems.len());
+    let mut is_chosen = vec![false; letter_elems.len()];
+    let mut dict_elem = 0;
+
+    // Walk the dictionary for the prefix
+    for &i in unit {
+        chosen.push(letter_elems[i]);
+        is_chosen[i] = true;
+
+        let next = args
+            .dictionary
+            .lookup_elem_letter_num(dict_elem, letter_elems[i]);
+
+        if !budget.visit() {
+            return;
+        }
+
+        // End of a word? (single letter words may be found by more than one work unit)
+        if let LetterNext::End | LetterNext::EndNext(_) = next {
+            result.insert(chosen_string(&chosen));
+        }
+
+        match next {
+            LetterNext::Next(e) | LetterNext::EndNext(e) => dict_elem = e as usize,
+            _ => return,
+        }
+    }
+
+    // Search the rest of the tree
+    find_words_rec(
+        args,
+        &mut chosen,
+        &mut is_chosen,
+        letter_elems,
+        dict_elem,
+        budget,
+        result,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use dictionary::Dictionary;
+
+    use crate::*;
+
+    #[test]
+    fn parallel() {
+        let dict = "\
+            a\n\
+            aa\n\
+            rut\n\
+            ruts\n\
+            rust\n\
+            rusty\n\
+            star\n\
+            stars\n\
+            tsar\n\
+            tsars\n\
+            ";
+
+        let dictionary = Dictionary::new_from_string(dict, Default::default(), false).unwrap();
+
+        for letters in ["ATRUSSA", "A"] {
+            for reuse_letters in [false, true] {
+                let single = find_words(SolverArgs {
+                    reuse_letters,
+                    ..SolverArgs::new(letters, &dictionary)
+                });
+
+                for threads in [2, 4, 100] {
+                    let outcome = search_words(SolverArgs {
+                        reuse_letters,
+                        threads,
+                        ..SolverArgs::new(letters, &dictionary)
+                    });
+
+                    assert_eq!(outcome.words, single);
+                    // No more threads than work units are started
+                    match (letters, threads) {
+                        ("A", _) => assert_eq!(outcome.stats.threads, 1),
+                        (_, 100) => assert!(outcome.stats.threads < 100),
+                        _ => assert_eq!(outcome.stats.threads, threads),
+                    }
+                }
+            }
+        }
+    }
+}
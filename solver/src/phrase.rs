@@ -0,0 +1,271 @@
+//! Multi word anagram (phrase) solver
+
+use dictionary::{Dictionary, LetterNext};
+
+use crate::chosen_string;
+use crate::counts::LetterCounts;
+
+/// Arguments for the multi word anagram solver
+pub struct PhraseArgs<'a> {
+    /// String of letters to use (must be upper case A-Z)
+    pub letters: &'a str,
+    /// Dictionary to use
+    pub dictionary: &'a Dictionary,
+    /// Lengths of each word in the phrase in order, eg. (5,4). Empty for any
+    pub enumeration: &'a [usize],
+    /// Minimum word length
+    pub min_len: usize,
+}
+
+/// Find phrases in the provided dictionary using every one of the provided letters exactly once.
+/// Without an enumeration the words in each phrase are in alphabetical order.
+/// Phrases are returned with the fewest words first then alphabetical
+pub fn find_phrases(args: PhraseArgs) -> Vec<Vec<String>> {
+    let mut result = Vec::new();
+
+    // Count of each letter available
+    let counts = LetterCounts::from_letters(args.letters);
+
+    let total = args.letters.len();
+
+    // Check the enumeration uses all of the letters
+    if total == 0
+        || (!args.enumeration.is_empty() && args.enumeration.iter().sum::<usize>() != total)
+    {
+        return result;
+    }
+
+    let mut state = PhraseState {
+        args: &args,
+        counts,
+        remaining: total,
+        word: Vec::with_capacity(total),
+        above_prev: false,
+        phrase: Vec::new(),
+    };
+
+    find_phrases_rec(&mut state, 0, &mut result);
+
+    result.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+
+    result
+}
+
+/// Phrase search state
+struct PhraseState<'a> {
+    args: &'a PhraseArgs<'a>,
+    /// Count of each letter still available
+    counts: LetterCounts,
+    /// Number of letters still available
+    remaining: usize,
+    /// Letter elements of the word being built
+    word: Vec<u8>,
+    /// Word being built is already alphabetically after the previous word
+    above_prev: bool,
+    /// Words chosen so far
+    phrase: Vec<String>,
+}
+
+impl PhraseState<'_> {
+    /// Returns the required length of the current word, if any
+    #[inline]
+    fn required_len(&self) -> Option<usize> {
+        self.args.enumeration.get(self.phrase.len()).copied()
+    }
+
+    /// Returns true if a word ending here can be added to the phrase
+    fn word_ok(&self) -> bool {
+        let len = self.word.len();
+
+        if len < self.args.min_len {
+            return false;
+        }
+
+        match self.required_len() {
+            Some(required) => len == required,
+            None => {
+                // Make sure there are enough letters left for another word
+                if self.remaining != 0 && self.remaining < self.args.min_len.max(1) {
+                    return false;
+                }
+
+                // Word must not be alphabetically before the previous word
+                self.above_prev
+                    || match self.phrase.last() {
+                        Some(prev) => len >= prev.len(),
+                        None => true,
+                    }
+            }
+        }
+    }
+
+    /// Returns None if the letter can't be added to the word being built, otherwise
+    /// whether the word will then be alphabetically after the previous word
+    fn letter_ok(&self, letter: u8) -> Option<bool> {
+        if let Some(required) = self.required_len() {
+            // Enumerated phrases don't need ordering
+            return if self.word.len() < required {
+                Some(true)
+            } else {
+                None
+            };
+        }
+
+        if self.above_prev {
+            return Some(true);
+        }
+
+        match self.phrase.last() {
+            Some(prev) => match prev.as_bytes().get(self.word.len()) {
+                Some(&c) => {
+                    let prev_letter = c - b'A';
+
+                    if letter < prev_letter {
+                        None
+                    } else {
+                        Some(letter > prev_letter)
+                    }
+                }
+                None => Some(true),
+            },
+            None => Some(true),
+        }
+    }
+}
+
+fn find_phrases_rec(state: &mut PhraseState, dict_elem: usize, result: &mut Vec<Vec<String>>) {
+    let dictionary = state.args.dictionary;
+
+    // Check a word of the required length can be reached from this node
+    if let Some(required) = state.required_len() {
+        if dictionary.elem_max_depth(dict_elem) < required - state.word.len() {
+            return;
+        }
+    }
+
+    for letter in 0..26u8 {
+        if state.counts.get(letter) == 0 {
+            continue;
+        }
+
+        let Some(above_prev) = state.letter_ok(letter) else {
+            continue;
+        };
+
+        let next = dictionary.lookup_elem_letter_num(dict_elem, letter);
+
+        if matches!(next, LetterNext::None) {
+            continue;
+        }
+
+        // Choose the letter
+        let saved_above_prev = state.above_prev;
+
+        state.counts.remove(letter);
+        state.remaining -= 1;
+        state.word.push(letter);
+        state.above_prev = above_prev;
+
+        // End of a word?
+        if matches!(next, LetterNext::End | LetterNext::EndNext(_)) && state.word_ok() {
+            // Add word to the phrase
+            let word = std::mem::take(&mut state.word);
+            state.phrase.push(chosen_string(&word));
+
+            if state.remaining == 0 {
+                result.push(state.phrase.clone());
+            } else {
+                // Restart at the root node for the next word
+                state.above_prev = false;
+
+                find_phrases_rec(state, 0, result);
+            }
+
+            state.phrase.pop();
+            state.word = word;
+            state.above_prev = above_prev;
+        }
+
+        // Recurse to next letter
+        if let LetterNext::Next(e) | LetterNext::EndNext(e) = next {
+            if state.remaining > 0 {
+                find_phrases_rec(state, e as usize, result);
+            }
+        }
+
+        // Unchoose the letter
+        state.word.pop();
+        state.remaining += 1;
+        state.counts.add(letter);
+        state.above_prev = saved_above_prev;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DICT: &str = "\
+        a\n\
+        hero\n\
+        horse\n\
+        hose\n\
+        man\n\
+        moan\n\
+        mohr\n\
+        norma\n\
+        shore\n\
+        xxx\n\
+        ";
+
+    #[test]
+    fn phrases() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let phrases = find_phrases(PhraseArgs {
+            letters: "MOANHORSE",
+            dictionary: &dictionary,
+            enumeration: &[],
+            min_len: 1,
+        });
+
+        assert_eq!(
+            phrases,
+            vec![
+                vec!["HORSE", "MOAN"],
+                vec!["HOSE", "NORMA"],
+                vec!["MOAN", "SHORE"],
+            ]
+        );
+    }
+
+    #[test]
+    fn enumerated_phrases() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let phrases = find_phrases(PhraseArgs {
+            letters: "MOANHORSE",
+            dictionary: &dictionary,
+            enumeration: &[5, 4],
+            min_len: 1,
+        });
+
+        assert_eq!(
+            phrases,
+            vec![
+                vec!["HORSE", "MOAN"],
+                vec!["NORMA", "HOSE"],
+                vec!["SHORE", "MOAN"],
+            ]
+        );
+
+        // Enumeration doesn't match the letters
+        assert!(find_phrases(PhraseArgs {
+            letters: "MOANHORSE",
+            dictionary: &dictionary,
+            enumeration: &[5, 5],
+            min_len: 1,
+        })
+        .is_empty());
+    }
+}
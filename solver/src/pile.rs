@@ -0,0 +1,210 @@
+//! Countdown letter piles
+
+use std::error::Error;
+use std::fmt;
+
+use crate::draw::{LetterFrequencies, PileFrequencies};
+use crate::rng::Rng;
+
+/// Letter pile kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PileKind {
+    /// Vowel pile
+    Vowel,
+    /// Consonant pile
+    Consonant,
+}
+
+impl PileKind {
+    /// Returns the name of the pile kind
+    pub fn name(&self) -> &'static str {
+        match self {
+            PileKind::Vowel => "vowel",
+            PileKind::Consonant => "consonant",
+        }
+    }
+}
+
+/// A pile of letter tiles which are drawn without replacement
+#[derive(Debug, Clone)]
+pub struct LetterPile {
+    tiles: Vec<char>,
+}
+
+impl LetterPile {
+    /// Creates a full letter pile from letter frequencies
+    pub fn new(frequencies: &LetterFrequencies) -> Self {
+        let tiles = frequencies
+            .iter()
+            .flat_map(|(c, count)| std::iter::repeat_n(c, count as usize))
+            .collect();
+
+        Self { tiles }
+    }
+
+    /// Returns the number of tiles left in the pile
+    pub fn remaining(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Draws a random tile from the pile. Returns None if the pile is empty
+    pub fn draw(&mut self, rng: &mut Rng) -> Option<char> {
+        if self.tiles.is_empty() {
+            None
+        } else {
+            let tile = rng.below(self.tiles.len());
+
+            Some(self.tiles.swap_remove(tile))
+        }
+    }
+}
+
+/// The vowel and consonant letter piles
+#[derive(Debug, Clone)]
+pub struct LetterPiles {
+    /// Vowel pile
+    pub vowels: LetterPile,
+    /// Consonant pile
+    pub consonants: LetterPile,
+}
+
+impl LetterPiles {
+    /// Creates full letter piles from pile frequencies
+    pub fn new(frequencies: &PileFrequencies) -> Self {
+        Self {
+            vowels: LetterPile::new(&frequencies.vowels),
+            consonants: LetterPile::new(&frequencies.consonants),
+        }
+    }
+
+    /// Creates full Countdown letter piles
+    pub fn countdown() -> Self {
+        Self::new(&PileFrequencies::countdown())
+    }
+
+    /// Draws a random tile from one of the piles
+    pub fn draw(&mut self, kind: PileKind, rng: &mut Rng) -> Result<char, DrawError> {
+        let pile = match kind {
+            PileKind::Vowel => &mut self.vowels,
+            PileKind::Consonant => &mut self.consonants,
+        };
+
+        pile.draw(rng).ok_or(DrawError::PileEmpty(kind))
+    }
+
+    /// Draws tiles for a sequence of vowel and consonant choices, eg. "VCCVCCVCC"
+    pub fn draw_sequence(&mut self, choices: &str, rng: &mut Rng) -> Result<String, DrawError> {
+        parse_choices(choices)?
+            .into_iter()
+            .map(|kind| self.draw(kind, rng))
+            .collect()
+    }
+}
+
+/// Parses a sequence of vowel (V) and consonant (C) choices. White space is ignored
+pub fn parse_choices(choices: &str) -> Result<Vec<PileKind>, DrawError> {
+    choices
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| match c.to_ascii_uppercase() {
+            'V' => Ok(PileKind::Vowel),
+            'C' => Ok(PileKind::Consonant),
+            _ => Err(DrawError::InvalidChoice {
+                position: i + 1,
+                character: c,
+            }),
+        })
+        .collect()
+}
+
+/// Letter drawing errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrawError {
+    /// A choice which is not V or C was found at the position (1 based)
+    InvalidChoice {
+        /// Position of the choice in the input (1 based)
+        position: usize,
+        /// The invalid character
+        character: char,
+    },
+    /// The pile has no tiles left
+    PileEmpty(PileKind),
+}
+
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrawError::InvalidChoice {
+                position,
+                character,
+            } => write!(
+                f,
+                "Invalid choice '{character}' at position {position}, choices must be V or C"
+            ),
+            DrawError::PileEmpty(kind) => write!(f, "The {} pile is empty", kind.name()),
+        }
+    }
+}
+
+impl Error for DrawError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::letters::is_vowel;
+
+    #[test]
+    fn draws() {
+        let mut piles = LetterPiles::countdown();
+
+        assert_eq!(piles.vowels.remaining(), 67);
+        assert_eq!(piles.consonants.remaining(), 74);
+
+        let letters = piles.draw_sequence("vccvc cvcc", &mut Rng::new(1)).unwrap();
+
+        assert_eq!(letters.len(), 9);
+        assert_eq!(
+            letters.chars().map(is_vowel).collect::<Vec<_>>(),
+            vec![true, false, false, true, false, false, true, false, false]
+        );
+        assert_eq!(piles.vowels.remaining(), 64);
+        assert_eq!(piles.consonants.remaining(), 68);
+
+        // Same seed gives the same draw
+        let again = LetterPiles::countdown()
+            .draw_sequence("VCCVCCVCC", &mut Rng::new(1))
+            .unwrap();
+
+        assert_eq!(letters, again);
+
+        assert_eq!(
+            piles.draw_sequence("VCX", &mut Rng::new(1)),
+            Err(DrawError::InvalidChoice {
+                position: 3,
+                character: 'X'
+            })
+        );
+    }
+
+    #[test]
+    fn empty_pile() {
+        let mut piles = LetterPiles::new(&PileFrequencies {
+            vowels: LetterFrequencies::new(&[('A', 1)]),
+            consonants: "B2 C1".parse().unwrap(),
+        });
+
+        let mut rng = Rng::new(5);
+
+        assert_eq!(piles.draw(PileKind::Vowel, &mut rng), Ok('A'));
+        assert_eq!(
+            piles.draw(PileKind::Vowel, &mut rng),
+            Err(DrawError::PileEmpty(PileKind::Vowel))
+        );
+
+        let mut consonants = piles.draw_sequence("CCC", &mut rng).unwrap().into_bytes();
+        consonants.sort();
+
+        assert_eq!(consonants, b"BBC");
+    }
+}
@@ -0,0 +1,171 @@
+//! Detailed word results
+
+use crate::scorer::Scorer;
+use crate::{find_words, SolverArgs};
+
+/// Detailed result for a word found by the solver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordResult {
+    /// The word
+    pub word: String,
+    /// Indexes of the input letters used to make the word, in word order
+    pub tiles: Vec<usize>,
+    /// Input letters not used by the word, in input order
+    pub unused: String,
+    /// Length of the word
+    pub len: usize,
+    /// Score for the word, if scored
+    pub score: Option<u32>,
+    /// Alphabetical position of the word in the dictionary, if known
+    pub word_id: Option<usize>,
+}
+
+impl WordResult {
+    /// Creates a word result for a word made from the input letters (both upper case A-Z).
+    /// Each letter of the word uses the first unused matching input letter. If no unused input
+    /// letter matches the first matching input letter is used again. Returns None if the word
+    /// contains a letter which is not in the input letters
+    pub fn new(word: &str, letters: &str) -> Option<Self> {
+        let letters = letters.as_bytes();
+
+        let mut used = vec![false; letters.len()];
+
+        let tiles = word
+            .bytes()
+            .map(|c| {
+                let tile = (0..letters.len())
+                    .find(|&i| letters[i] == c && !used[i])
+                    .or_else(|| letters.iter().position(|l| *l == c))?;
+
+                used[tile] = true;
+
+                Some(tile)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let unused = letters
+            .iter()
+            .zip(used)
+            .filter(|(_, used)| !used)
+            .map(|(l, _)| *l as char)
+            .collect();
+
+        Some(Self {
+            word: word.to_string(),
+            tiles,
+            unused,
+            len: word.len(),
+            score: None,
+            word_id: None,
+        })
+    }
+
+    /// Sets the score for the word
+    pub fn set_score(&mut self, scorer: &dyn Scorer) {
+        self.score = Some(scorer.score(&self.word));
+    }
+}
+
+/// Find words in the provided dictionary using the provided letters returning detailed results.
+/// If a scorer is given words are scored and returned highest score first, otherwise longest
+/// first. Words of equal rank are returned in alphabetical order
+pub fn find_word_results(args: SolverArgs, scorer: Option<&dyn Scorer>) -> Vec<WordResult> {
+    let letters = args.letters.clone();
+    let dictionary = args.dictionary;
+
+    let mut results = find_words(args)
+        .into_iter()
+        .filter_map(|word| {
+            let mut result = WordResult::new(&word, &letters)?;
+
+            result.word_id = dictionary.word_id(&word);
+
+            if let Some(scorer) = scorer {
+                result.set_score(scorer);
+            }
+
+            Some(result)
+        })
+        .collect::<Vec<_>>();
+
+    results.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| b.len.cmp(&a.len))
+            .then_with(|| a.word.cmp(&b.word))
+    });
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use dictionary::Dictionary;
+
+    use super::*;
+    use crate::scorer::CountdownScorer;
+
+    #[test]
+    fn results() {
+        let dictionary =
+            Dictionary::new_from_string("rust\nrut\nruts\ntsar", Default::default(), false)
+                .unwrap();
+
+        let results = find_word_results(
+            SolverArgs::new("SUTRAT", &dictionary),
+            Some(&CountdownScorer),
+        );
+
+        assert_eq!(
+            results,
+            vec![
+                WordResult {
+                    word: "RUST".into(),
+                    tiles: vec![3, 1, 0, 2],
+                    unused: "AT".into(),
+                    len: 4,
+                    score: Some(4),
+                    word_id: Some(0),
+                },
+                WordResult {
+                    word: "RUTS".into(),
+                    tiles: vec![3, 1, 2, 0],
+                    unused: "AT".into(),
+                    len: 4,
+                    score: Some(4),
+                    word_id: Some(2),
+                },
+                WordResult {
+                    word: "TSAR".into(),
+                    tiles: vec![2, 0, 4, 3],
+                    unused: "UT".into(),
+                    len: 4,
+                    score: Some(4),
+                    word_id: Some(3),
+                },
+                WordResult {
+                    word: "RUT".into(),
+                    tiles: vec![3, 1, 2],
+                    unused: "SAT".into(),
+                    len: 3,
+                    score: Some(3),
+                    word_id: Some(1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reused() {
+        let result = WordResult::new("RURU", "UR").unwrap();
+
+        assert_eq!(result.tiles, vec![1, 0, 1, 0]);
+        assert_eq!(result.unused, "");
+    }
+
+    #[test]
+    fn unavailable() {
+        assert_eq!(WordResult::new("RUSTY", "SUTRA"), None);
+        assert_eq!(WordResult::new("RUST", "SUTRA").unwrap().tiles.len(), 4);
+    }
+}
@@ -0,0 +1,83 @@
+//! Seeded random number generator
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Small seeded pseudo random number generator (SplitMix64). The sequence generated for
+/// a seed will never change so seeds can be shared
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a random number generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Creates a random number generator seeded from the system time
+    pub fn from_time() -> Self {
+        Self::new(time_seed())
+    }
+
+    /// Returns the next random 64 bit number
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in the range 0..n (n must be greater than zero)
+    pub fn below(&mut self, n: usize) -> usize {
+        // Multiply and shift to avoid modulo bias
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Returns a random number in the range 0.0..1.0
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random element from a slice
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len())])
+        }
+    }
+}
+
+/// Returns a seed derived from the system time
+pub fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence() {
+        // Sequences must never change for a seed
+        let mut rng = Rng::new(1234567);
+
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+
+        let mut rng = Rng::new(0);
+
+        for _ in 0..1000 {
+            assert!(rng.below(9) < 9);
+            assert!((0.0..1.0).contains(&rng.next_f64()));
+        }
+
+        assert_eq!(rng.choose::<u8>(&[]), None);
+        assert_eq!(rng.choose(&[5]), Some(&5));
+    }
+}
@@ -0,0 +1,158 @@
+//! Word scoring models
+
+use std::cmp::Ordering;
+
+/// Scoring model for words
+pub trait Scorer {
+    /// Returns the score for a word (must be upper case A-Z)
+    fn score(&self, word: &str) -> u32;
+
+    /// Returns the highest score achievable by any word of at most `len` letters
+    fn max_score(&self, len: usize) -> u32;
+}
+
+/// Scores words by length
+pub struct LengthScorer;
+
+impl Scorer for LengthScorer {
+    fn score(&self, word: &str) -> u32 {
+        word.len() as u32
+    }
+
+    fn max_score(&self, len: usize) -> u32 {
+        len.min(u32::MAX as usize) as u32
+    }
+}
+
+/// Scores words using the Countdown rules (one point per letter, 18 points for a nine letter word)
+pub struct CountdownScorer;
+
+impl Scorer for CountdownScorer {
+    fn score(&self, word: &str) -> u32 {
+        match word.len() {
+            9 => 18,
+            len => len as u32,
+        }
+    }
+
+    fn max_score(&self, len: usize) -> u32 {
+        if len >= 9 {
+            LengthScorer.max_score(len).max(18)
+        } else {
+            len as u32
+        }
+    }
+}
+
+/// Scores words using the sum of Scrabble tile values
+pub struct ScrabbleScorer;
+
+/// Scrabble tile values for A-Z
+const SCRABBLE_VALUES: [u8; 26] = [
+    1, 3, 3, 2, 1, 4, 2, 4, 1, 8, 5, 1, 3, 1, 1, 3, 10, 1, 1, 1, 1, 4, 4, 8, 4, 10,
+];
+
+impl ScrabbleScorer {
+    /// Returns the Scrabble tile value of a letter (must be upper case A-Z)
+    pub fn letter_value(letter: char) -> u32 {
+        SCRABBLE_VALUES[(letter as u8 - b'A') as usize] as u32
+    }
+}
+
+impl Scorer for ScrabbleScorer {
+    fn score(&self, word: &str) -> u32 {
+        word.chars().map(Self::letter_value).sum()
+    }
+
+    fn max_score(&self, len: usize) -> u32 {
+        LengthScorer.max_score(len).saturating_mul(10)
+    }
+}
+
+/// Scores words by the square of their length
+pub struct LengthSquaredScorer;
+
+impl Scorer for LengthSquaredScorer {
+    fn score(&self, word: &str) -> u32 {
+        (word.len() * word.len()) as u32
+    }
+
+    fn max_score(&self, len: usize) -> u32 {
+        let len = LengthScorer.max_score(len);
+
+        len.saturating_mul(len)
+    }
+}
+
+/// A word and its score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoredWord {
+    /// The word
+    pub word: String,
+    /// Score for the word
+    pub score: u32,
+}
+
+impl ScoredWord {
+    /// Orders words by highest score, then longest, then alphabetical
+    pub fn rank_cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .cmp(&self.score)
+            .then_with(|| other.word.len().cmp(&self.word.len()))
+            .then_with(|| self.word.cmp(&other.word))
+    }
+}
+
+/// Scores a list of words and ranks them by highest score, then longest, then alphabetical
+pub fn score_words(words: Vec<String>, scorer: &dyn Scorer) -> Vec<ScoredWord> {
+    let mut scored = words
+        .into_iter()
+        .map(|word| ScoredWord {
+            score: scorer.score(&word),
+            word,
+        })
+        .collect::<Vec<_>>();
+
+    scored.sort_by(ScoredWord::rank_cmp);
+
+    scored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores() {
+        assert_eq!(LengthScorer.score("RUST"), 4);
+        assert_eq!(CountdownScorer.score("RUST"), 4);
+        assert_eq!(CountdownScorer.score("COUNTDOWN"), 18);
+        assert_eq!(ScrabbleScorer.score("QUIZ"), 22);
+        assert_eq!(LengthSquaredScorer.score("RUST"), 16);
+
+        assert_eq!(CountdownScorer.max_score(8), 8);
+        assert_eq!(CountdownScorer.max_score(9), 18);
+        assert_eq!(CountdownScorer.max_score(20), 20);
+    }
+
+    #[test]
+    fn ranking() {
+        let words = vec!["ZAX".into(), "RUST".into(), "RUTS".into(), "AXE".into()];
+
+        let ranked = score_words(words, &ScrabbleScorer)
+            .into_iter()
+            .map(|s| (s.word, s.score))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ranked,
+            vec![
+                ("ZAX".into(), 19),
+                ("AXE".into(), 10),
+                ("RUST".into(), 4),
+                ("RUTS".into(), 4)
+            ]
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Monte Carlo simulation of vowel and consonant choices
+
+use dictionary::Dictionary;
+
+use crate::backend::{SolverBackend, TrieBackend};
+use crate::batch::{solve_batch, BatchArgs};
+use crate::draw::PileFrequencies;
+use crate::pile::{DrawError, LetterPiles, PileKind};
+use crate::rng::Rng;
+use crate::scorer::{CountdownScorer, Scorer};
+
+/// Arguments for a simulation
+pub struct SimulationArgs<'a> {
+    /// Dictionary to use
+    pub dictionary: &'a Dictionary,
+    /// Pile frequencies to draw from
+    pub piles: PileFrequencies,
+    /// Number of vowels drawn each round
+    pub vowels: usize,
+    /// Number of consonants drawn each round
+    pub consonants: usize,
+    /// Number of rounds to simulate
+    pub rounds: usize,
+    /// Random number seed
+    pub seed: u64,
+    /// Number of threads to solve with (0 to use all available cores)
+    pub threads: usize,
+    /// Search implementation to use
+    pub backend: &'a dyn SolverBackend,
+}
+
+impl<'a> SimulationArgs<'a> {
+    /// Creates simulation arguments for a vowel and consonant split with default options
+    pub fn new(dictionary: &'a Dictionary, vowels: usize, consonants: usize) -> Self {
+        Self {
+            dictionary,
+            piles: PileFrequencies::countdown(),
+            vowels,
+            consonants,
+            rounds: 1000,
+            seed: 0,
+            threads: 1,
+            backend: &TrieBackend,
+        }
+    }
+}
+
+/// Simulation results for a vowel and consonant split
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitStats {
+    /// Number of vowels drawn each round
+    pub vowels: usize,
+    /// Number of consonants drawn each round
+    pub consonants: usize,
+    /// Number of rounds simulated
+    pub rounds: usize,
+    /// Number of rounds for each best word length (indexed by length)
+    pub best_lens: Vec<usize>,
+    /// Total Countdown score of the best word in each round
+    pub total_score: u64,
+}
+
+impl SplitStats {
+    /// Returns the proportion of rounds where the best word has the given length
+    pub fn len_chance(&self, len: usize) -> f64 {
+        self.proportion(self.best_lens.get(len).copied().unwrap_or(0) as f64)
+    }
+
+    /// Returns the proportion of rounds where a word using every letter can be made
+    pub fn full_chance(&self) -> f64 {
+        self.len_chance(self.vowels + self.consonants)
+    }
+
+    /// Returns the mean length of the best word
+    pub fn mean_best_len(&self) -> f64 {
+        let total = self
+            .best_lens
+            .iter()
+            .enumerate()
+            .map(|(len, count)| len * count)
+            .sum::<usize>();
+
+        self.proportion(total as f64)
+    }
+
+    /// Returns the expected Countdown score of the best word
+    pub fn expected_score(&self) -> f64 {
+        self.proportion(self.total_score as f64)
+    }
+
+    fn proportion(&self, value: f64) -> f64 {
+        if self.rounds == 0 {
+            0.0
+        } else {
+            value / self.rounds as f64
+        }
+    }
+}
+
+/// Simulates rounds drawing a fixed number of vowels and consonants and finds the best word for
+/// each. The letter sets are drawn from the seed before solving so results only depend on the seed.
+/// Returns an error if a pile has too few tiles for the draw
+pub fn simulate_split(args: &SimulationArgs) -> Result<SplitStats, DrawError> {
+    // Check the piles can supply every round
+    for (kind, pile, needed) in [
+        (PileKind::Vowel, &args.piles.vowels, args.vowels),
+        (PileKind::Consonant, &args.piles.consonants, args.consonants),
+    ] {
+        if (pile.total() as usize) < needed {
+            Err(DrawError::PileEmpty(kind))?;
+        }
+    }
+
+    let mut rng = Rng::new(args.seed);
+
+    // Draw the letter sets
+    let choices = std::iter::repeat_n(PileKind::Vowel, args.vowels)
+        .chain(std::iter::repeat_n(PileKind::Consonant, args.consonants))
+        .collect::<Vec<_>>();
+
+    let letter_sets = (0..args.rounds)
+        .map(|_| {
+            let mut piles = LetterPiles::new(&args.piles);
+
+            choices
+                .iter()
+                .map(|kind| piles.draw(*kind, &mut rng))
+                .collect::<Result<String, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Solve them
+    let outcome = solve_batch(
+        &letter_sets,
+        &BatchArgs {
+            threads: args.threads,
+            backend: args.backend,
+            ..BatchArgs::new(args.dictionary)
+        },
+    );
+
+    let mut stats = SplitStats {
+        vowels: args.vowels,
+        consonants: args.consonants,
+        rounds: args.rounds,
+        best_lens: vec![0; args.vowels + args.consonants + 1],
+        total_score: 0,
+    };
+
+    for result in outcome.results {
+        let words = result.words.unwrap_or_default();
+
+        let best_len = words.iter().map(|w| w.len()).max().unwrap_or(0);
+
+        if let Some(count) = stats.best_lens.get_mut(best_len) {
+            *count += 1;
+        }
+
+        stats.total_score += words
+            .iter()
+            .map(|w| CountdownScorer.score(w) as u64)
+            .max()
+            .unwrap_or(0);
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::LetterFrequencies;
+
+    fn piles() -> PileFrequencies {
+        PileFrequencies {
+            vowels: LetterFrequencies::new(&[('A', 10)]),
+            consonants: LetterFrequencies::new(&[('T', 10), ('R', 10)]),
+        }
+    }
+
+    #[test]
+    fn simulate() {
+        let dictionary =
+            Dictionary::new_from_string("at\nta\ntat\nart\ntart\nrat", Default::default(), false)
+                .unwrap();
+
+        let stats = simulate_split(&SimulationArgs {
+            piles: piles(),
+            rounds: 200,
+            seed: 7,
+            ..SimulationArgs::new(&dictionary, 1, 3)
+        })
+        .unwrap();
+
+        assert_eq!(stats.rounds, 200);
+        assert_eq!(stats.best_lens.iter().sum::<usize>(), 200);
+        assert!(stats.best_lens[4] > 0);
+
+        // Countdown scores are the word length for short words
+        let total_len = stats
+            .best_lens
+            .iter()
+            .enumerate()
+            .map(|(len, count)| (len * count) as u64)
+            .sum::<u64>();
+
+        assert_eq!(stats.total_score, total_len);
+        assert_eq!(stats.full_chance(), stats.len_chance(4));
+        assert_eq!(stats.expected_score(), stats.mean_best_len());
+
+        // Same seed gives the same results
+        let again = simulate_split(&SimulationArgs {
+            piles: piles(),
+            rounds: 200,
+            seed: 7,
+            threads: 2,
+            ..SimulationArgs::new(&dictionary, 1, 3)
+        })
+        .unwrap();
+
+        assert_eq!(stats, again);
+
+        // Not enough vowels for the split
+        assert_eq!(
+            simulate_split(&SimulationArgs {
+                piles: piles(),
+                ..SimulationArgs::new(&dictionary, 11, 3)
+            }),
+            Err(DrawError::PileEmpty(PileKind::Vowel))
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! Longest word first search returning only the best words
+
+use std::cmp::Reverse;
+
+use dictionary::LetterNext;
+
+use crate::limits::Budget;
+use crate::scorer::{LengthScorer, ScoredWord, Scorer};
+use crate::trace::trace_lookup;
+use crate::{chosen_string, letter_elems, SolverArgs};
+
+/// Find the best `count` words in the provided dictionary using the provided letters.
+/// Words are returned longest first then alphabetical. Dictionary branches which cannot
+/// produce a word good enough to be returned are not searched
+pub fn find_top_words(args: SolverArgs, count: usize) -> Vec<String> {
+    find_top_scored_words(args, count, &LengthScorer)
+        .into_iter()
+        .map(|s| s.word)
+        .collect()
+}
+
+/// Find the best `count` words in the provided dictionary using the provided letters and scorer.
+/// Words are returned highest score first, then longest, then alphabetical.
+/// If a search limit is reached the best words found so far are returned
+pub fn find_top_scored_words(
+    args: SolverArgs,
+    count: usize,
+    scorer: &dyn Scorer,
+) -> Vec<ScoredWord> {
+    search_top_words(args, count, scorer).words
+}
+
+/// Result of a best words search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopOutcome {
+    /// Best words found, highest score first, then longest, then alphabetical
+    pub words: Vec<ScoredWord>,
+    /// The search was stopped by one of the search limits before completing
+    pub truncated: bool,
+}
+
+/// Find the best `count` words in the provided dictionary using the provided letters and scorer,
+/// reporting whether a search limit was reached
+pub fn search_top_words(args: SolverArgs, count: usize, scorer: &dyn Scorer) -> TopOutcome {
+    let mut top = TopWords::new(count, scorer);
+
+    if count == 0 {
+        return TopOutcome {
+            words: top.words,
+            truncated: false,
+        };
+    }
+
+    // Dictionary entry element numbers for each letter
+    let letter_elems = letter_elems(&args);
+
+    // Vector of chosen letter elements
+    let mut chosen = Vec::with_capacity(letter_elems.len());
+
+    // Been chosen indicators
+    let mut is_chosen = vec![false; letter_elems.len()];
+
+    // Search budget
+    let budget = Budget::new(&args.limits);
+
+    // Start search recursion
+    find_top_words_rec(
+        &args,
+        &mut chosen,
+        &mut is_chosen,
+        &letter_elems,
+        0,
+        &budget,
+        &mut top,
+    );
+
+    TopOutcome {
+        words: top.words,
+        truncated: budget.stopped(),
+    }
+}
+
+fn find_top_words_rec(
+    args: &SolverArgs,
+    chosen: &mut Vec<u8>,
+    is_chosen: &mut Vec<bool>,
+    letter_elems: &[u8],
+    dict_elem: usize,
+    budget: &Budget,
+    top: &mut TopWords,
+) {
+    // Number of letters left to choose from
+    let remaining = if args.reuse_letters {
+        usize::MAX
+    } else {
+        letter_elems.len() - chosen.len()
+    };
+
+    // Build list of candidate letters with the best word length achievable for each
+    let mut tried = [false; 26];
+    let mut candidates = Vec::with_capacity(letter_elems.len());
+
+    for (i, &letter) in letter_elems.iter().enumerate() {
+        if is_chosen[i] && !args.reuse_letters {
+            // This letter has already been chosen
+            continue;
+        }
+
+        if tried[letter as usize] {
+            // Another tile with this letter has already been considered at this level
+            continue;
+        }
+
+        tried[letter as usize] = true;
+
+        let next = args.dictionary.lookup_elem_letter_num(dict_elem, letter);
+
+        if !budget.visit() {
+            // Search limit reached
+            return;
+        }
+
+        let bound = match next {
+            LetterNext::None => continue,
+            LetterNext::End => chosen.len() + 1,
+            LetterNext::Next(e) | LetterNext::EndNext(e) => {
+                chosen.len()
+                    + 1
+                    + args
+                        .dictionary
+                        .elem_max_depth(e as usize)
+                        .min(remaining - 1)
+            }
+        };
+
+        candidates.push((bound, i, next));
+    }
+
+    // Search the most promising letters first
+    candidates.sort_by_key(|c| Reverse(c.0));
+
+    for (bound, i, next) in candidates {
+        if budget.stopped() {
+            break;
+        }
+
+        // Best score achievable on this branch
+        let bound = top.scorer.max_score(bound);
+
+        if !top.can_improve(bound) {
+            // Candidates are sorted so none of the remaining ones can improve either
+            break;
+        }
+
+        // Add chosen letter to the chosen list
+        chosen.push(letter_elems[i]);
+
+        // End of a word?
+        if let LetterNext::End | LetterNext::EndNext(_) = next {
+            top.add(chosen_string(chosen));
+        }
+
+        // Can a better word be found on this branch?
+        let descend =
+            matches!(next, LetterNext::Next(_) | LetterNext::EndNext(_)) && top.can_improve(bound);
+
+        if let Some(sink) = args.trace {
+            trace_lookup(sink, chosen, next, descend);
+        }
+
+        // Recurse to next letter
+        if let (true, LetterNext::Next(e) | LetterNext::EndNext(e)) = (descend, next) {
+            is_chosen[i] = true;
+
+            find_top_words_rec(
+                args,
+                chosen,
+                is_chosen,
+                letter_elems,
+                e as usize,
+                budget,
+                top,
+            );
+
+            is_chosen[i] = false;
+        }
+
+        chosen.pop();
+    }
+}
+
+/// Ordered list of the best words found so far
+struct TopWords<'a> {
+    count: usize,
+    scorer: &'a dyn Scorer,
+    words: Vec<ScoredWord>,
+}
+
+impl<'a> TopWords<'a> {
+    fn new(count: usize, scorer: &'a dyn Scorer) -> Self {
+        Self {
+            count,
+            scorer,
+            words: Vec::with_capacity(count + 1),
+        }
+    }
+
+    /// Returns true if a word scoring `score` could be added to the list
+    #[inline]
+    fn can_improve(&self, score: u32) -> bool {
+        self.words.len() < self.count || score >= self.words[self.words.len() - 1].score
+    }
+
+    /// Adds a word to the list if it is good enough
+    fn add(&mut self, word: String) {
+        let word = ScoredWord {
+            score: self.scorer.score(&word),
+            word,
+        };
+
+        // Find insert position
+        match self.words.binary_search_by(|w| w.rank_cmp(&word)) {
+            Ok(_) => (),
+            Err(pos) => {
+                if pos < self.count {
+                    self.words.insert(pos, word);
+                    self.words.truncate(self.count);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dictionary::Dictionary;
+
+    use super::*;
+    use crate::find_words;
+    use crate::limits::SearchLimits;
+    use crate::scorer::ScrabbleScorer;
+
+    const DICT: &str = "\
+        aaa\n\
+        rut\n\
+        ruts\n\
+        rust\n\
+        rusty\n\
+        star\n\
+        stars\n\
+        tsar\n\
+        tsars\n\
+        xxx\n\
+        ";
+
+    #[test]
+    fn top() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let args = || SolverArgs::new("TRUSSA", &dictionary);
+
+        assert_eq!(find_top_words(args(), 1), vec!["STARS"]);
+        assert_eq!(find_top_words(args(), 3), vec!["STARS", "TSARS", "RUST"]);
+        assert!(find_top_words(args(), 0).is_empty());
+
+        // Asking for more than exist should return the same as find_words
+        let mut words = find_words(args());
+
+        words.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+        assert_eq!(find_top_words(args(), 100), words);
+    }
+
+    #[test]
+    fn top_reuse() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let words = find_top_words(
+            SolverArgs {
+                reuse_letters: true,
+                ..SolverArgs::new("ATRS", &dictionary)
+            },
+            2,
+        );
+
+        assert_eq!(words, vec!["STARS", "TSARS"]);
+    }
+
+    #[test]
+    fn top_truncated() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let outcome = search_top_words(SolverArgs::new("TRUSSA", &dictionary), 3, &LengthScorer);
+
+        assert!(!outcome.truncated);
+        assert_eq!(outcome.words.len(), 3);
+
+        let outcome = search_top_words(
+            SolverArgs {
+                limits: SearchLimits {
+                    max_nodes: Some(2),
+                    ..Default::default()
+                },
+                ..SolverArgs::new("TRUSSA", &dictionary)
+            },
+            3,
+            &LengthScorer,
+        );
+
+        assert!(outcome.truncated);
+    }
+
+    #[test]
+    fn top_scored() {
+        let dictionary = Dictionary::new_from_string(DICT, Default::default(), false).unwrap();
+
+        let words = find_top_scored_words(
+            SolverArgs::new("AAAXXXRUT", &dictionary),
+            2,
+            &ScrabbleScorer,
+        );
+
+        assert_eq!(
+            words,
+            vec![
+                ScoredWord {
+                    word: "XXX".into(),
+                    score: 24
+                },
+                ScoredWord {
+                    word: "AAA".into(),
+                    score: 3
+                }
+            ]
+        );
+    }
+}
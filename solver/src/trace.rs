@@ -0,0 +1,238 @@
+//! Search tracing
+
+use std::io::{self, Stdout, Write};
+use std::sync::Mutex;
+
+use dictionary::LetterNext;
+
+/// Search trace event kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceKind {
+    /// Descending the dictionary tree to the next letter
+    Descend,
+    /// A word has been found
+    Word,
+    /// No words continue with the letter path
+    DeadEnd,
+}
+
+impl TraceKind {
+    /// Returns the name of the event kind
+    pub fn name(&self) -> &'static str {
+        match self {
+            TraceKind::Descend => "descend",
+            TraceKind::Word => "word",
+            TraceKind::DeadEnd => "dead_end",
+        }
+    }
+}
+
+/// Search trace event. One event is generated for each dictionary tree lookup apart from
+/// `LetterNext::EndNext` which generates a `Word` event followed by a `Descend` event
+/// if the search continues
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// Event kind
+    pub kind: TraceKind,
+    /// Letters chosen so far
+    pub path: String,
+    /// Result of the dictionary tree lookup for the last letter in the path
+    pub next: LetterNext,
+}
+
+/// Receives search trace events
+pub trait TraceSink: Sync {
+    /// Called for each trace event
+    fn event(&self, event: &TraceEvent);
+}
+
+/// Writes trace events as indented text
+pub struct TextTraceSink<W: Write + Send> {
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> TextTraceSink<W> {
+    /// Creates a new text trace sink writing to `out`
+    pub fn new(out: W) -> Self {
+        Self {
+            out: Mutex::new(out),
+        }
+    }
+
+    /// Returns the writer
+    pub fn into_inner(self) -> W {
+        self.out.into_inner().unwrap()
+    }
+}
+
+impl TextTraceSink<Stdout> {
+    /// Creates a new text trace sink writing to stdout
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write + Send> TraceSink for TextTraceSink<W> {
+    fn event(&self, event: &TraceEvent) {
+        // Only write one line per lookup
+        if event.kind == TraceKind::Descend && matches!(event.next, LetterNext::EndNext(_)) {
+            return;
+        }
+
+        let indent = event.path.len() - 1;
+
+        let mut out = self.out.lock().unwrap();
+
+        let _ = writeln!(out, "{:indent$}{} ({:?})", "", event.path, event.next);
+    }
+}
+
+/// Writes trace events as JSON lines
+pub struct JsonTraceSink<W: Write + Send> {
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonTraceSink<W> {
+    /// Creates a new JSON lines trace sink writing to `out`
+    pub fn new(out: W) -> Self {
+        Self {
+            out: Mutex::new(out),
+        }
+    }
+
+    /// Returns the writer
+    pub fn into_inner(self) -> W {
+        self.out.into_inner().unwrap()
+    }
+}
+
+impl<W: Write + Send> TraceSink for JsonTraceSink<W> {
+    fn event(&self, event: &TraceEvent) {
+        let (next, node) = match event.next {
+            LetterNext::None => ("none", None),
+            LetterNext::Next(e) => ("next", Some(e)),
+            LetterNext::End => ("end", None),
+            LetterNext::EndNext(e) => ("end_next", Some(e)),
+        };
+
+        let node = match node {
+            Some(e) => format!(",\"node\":{e}"),
+            None => String::new(),
+        };
+
+        let mut out = self.out.lock().unwrap();
+
+        // Paths only contain A-Z so need no escaping
+        let _ = writeln!(
+            out,
+            "{{\"event\":\"{}\",\"path\":\"{}\",\"next\":\"{}\"{}}}",
+            event.kind.name(),
+            event.path,
+            next,
+            node
+        );
+    }
+}
+
+/// Records trace events in memory
+#[derive(Default)]
+pub struct RecordingTraceSink {
+    events: Mutex<Vec<TraceEvent>>,
+}
+
+impl RecordingTraceSink {
+    /// Creates a new recording trace sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded events
+    pub fn into_events(self) -> Vec<TraceEvent> {
+        self.events.into_inner().unwrap()
+    }
+}
+
+impl TraceSink for RecordingTraceSink {
+    fn event(&self, event: &TraceEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
+
+/// Sends trace events for a dictionary lookup
+#[cold]
+pub(crate) fn trace_lookup(sink: &dyn TraceSink, chosen: &[u8], next: LetterNext, descend: bool) {
+    let path = crate::chosen_string(chosen);
+
+    let send = |kind| {
+        sink.event(&TraceEvent {
+            kind,
+            path: path.clone(),
+            next,
+        })
+    };
+
+    match next {
+        LetterNext::None => send(TraceKind::DeadEnd),
+        LetterNext::End => send(TraceKind::Word),
+        LetterNext::Next(_) => send(TraceKind::Descend),
+        LetterNext::EndNext(_) => {
+            send(TraceKind::Word);
+
+            if descend {
+                send(TraceKind::Descend);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_and_json() {
+        let events = [
+            TraceEvent {
+                kind: TraceKind::Descend,
+                path: "R".into(),
+                next: LetterNext::Next(1),
+            },
+            TraceEvent {
+                kind: TraceKind::Word,
+                path: "RU".into(),
+                next: LetterNext::EndNext(2),
+            },
+            TraceEvent {
+                kind: TraceKind::Descend,
+                path: "RU".into(),
+                next: LetterNext::EndNext(2),
+            },
+            TraceEvent {
+                kind: TraceKind::DeadEnd,
+                path: "RUX".into(),
+                next: LetterNext::None,
+            },
+        ];
+
+        let text = TextTraceSink::new(Vec::new());
+        let json = JsonTraceSink::new(Vec::new());
+
+        for event in &events {
+            text.event(event);
+            json.event(event);
+        }
+
+        assert_eq!(
+            String::from_utf8(text.into_inner()).unwrap(),
+            "R (Next(1))\n RU (EndNext(2))\n  RUX (None)\n"
+        );
+
+        assert_eq!(
+            String::from_utf8(json.into_inner()).unwrap(),
+            "{\"event\":\"descend\",\"path\":\"R\",\"next\":\"next\",\"node\":1}\n\
+             {\"event\":\"word\",\"path\":\"RU\",\"next\":\"end_next\",\"node\":2}\n\
+             {\"event\":\"descend\",\"path\":\"RU\",\"next\":\"end_next\",\"node\":2}\n\
+             {\"event\":\"dead_end\",\"path\":\"RUX\",\"next\":\"none\"}\n"
+        );
+    }
+}